@@ -23,6 +23,13 @@ impl Parameters {
             .1
             .clone()
     }
+
+    fn find_token(&self, name: &str) -> Option<syn::Ident> {
+        self.parameters
+            .iter()
+            .find(|e| e.0 == name)
+            .map(|e| e.1.clone())
+    }
 }
 
 impl Parse for Parameters {
@@ -56,7 +63,7 @@ pub fn display_macro_derive(input: TokenStream) -> TokenStream {
     impl_display_macro(&ast)
 }
 
-#[proc_macro_derive(SiAddSubtract)]
+#[proc_macro_derive(SiAddSubtract, attributes(parameters))]
 pub fn add_subtract_macro_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
@@ -68,9 +75,69 @@ pub fn add_subtract_macro_derive(input: TokenStream) -> TokenStream {
 
 fn impl_add_subtract_macro(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
+    let unit = find_unit(name.to_string());
+    let label: &'static str = unit.label;
+    let symbol: &'static str = unit.symbol;
+    let ascii_symbol = ascii_symbol(symbol);
+    let aliases = ALIASES
+        .iter()
+        .filter(|(name, _)| *name == unit.name)
+        .map(|(_, alias)| *alias);
+    let factors = unit_factors(symbol).into_iter().map(|(symbol, power)| {
+        quote::quote! { crate::render::Factor { symbol: #symbol, power: #power } }
+    });
+    let singular: &'static str = unit.singular;
+    let plural: &'static str = unit.plural;
+    let prefixable = unit.prefixable;
+
+    // the optional lower limit of the physical domain (e.g. absolute zero)
+    let minimum_check = match find_parameters(ast) {
+        Some(parameters) => match (
+            parameters.find_token("minimum"),
+            parameters.find_token("minimum_error"),
+        ) {
+            (Some(minimum), Some(minimum_error)) => quote::quote! {
+                if self.native < #minimum {
+                    return Err(crate::UnitError::new::<Self>(
+                        self.native,
+                        crate::UnitErrorKind::#minimum_error,
+                    ));
+                }
+            },
+            (None, None) => quote::quote! {},
+            _ => panic!("minimum and minimum_error must be used together"),
+        },
+        None => quote::quote! {},
+    };
+
+    // `Scalar::atan2` keeps returning a `Scalar`, as it did before this was generated
+    let atan2 = if name == "Scalar" {
+        quote::quote! {}
+    } else {
+        quote::quote! {
+            /// The angle of the vector `(other, self)` from the `other` axis
+            pub fn atan2(&self, other: Self) -> crate::PlaneAngle {
+                #[cfg(feature = "f32")]
+                {
+                    crate::PlaneAngle::from(libm::atan2f(self.native, other.native))
+                }
+
+                #[cfg(not(feature = "f32"))]
+                {
+                    crate::PlaneAngle::from(libm::atan2(self.native, other.native))
+                }
+            }
+        }
+    };
+
     let generate = quote::quote! {
         impl core::cmp::PartialEq for #name {
             fn eq(&self, rhs: &Self) -> bool {
+                // also covers zero, which has no leading digit to compare
+                if self.native == rhs.native {
+                    return true;
+                }
+
                 #[cfg(feature = "f32")]
                 {
                     let lhs_log = libm::floorf(libm::log10f(self.native)) as i32;
@@ -100,10 +167,89 @@ fn impl_add_subtract_macro(ast: &syn::DeriveInput) -> TokenStream {
                 Self{ native }
             }
 
+            /// Chooses how the value is formatted (prefixes, symbols, locale, ...)
+            pub fn format(&self) -> crate::format::Formatted<'static, Self> {
+                crate::format::Formatted::new(*self)
+            }
+
+            /// Formats the value in engineering notation with an SI prefix (e.g. `"4.7 kΩ"`)
+            pub fn engineering(&self) -> crate::format::Formatted<'static, Self> {
+                self.format().symbols().engineering()
+            }
+
+            /// Formats the value in the unit `system` prefers (e.g. `"10000 feet"` in aviation)
+            pub fn present(&self, system: &crate::system::UnitSystem<'_>) -> crate::format::Formatted<'static, Self> {
+                self.format().system(system)
+            }
+
             pub fn abs(&self) -> Self {
                 Self{ native: self.native.abs() }
             }
 
+            pub fn try_new(native: crate::NativeType) -> Result<Self, crate::UnitError> {
+                crate::Quantity::validate(Self{ native })
+            }
+
+            #atan2
+
+            pub fn hypot(&self, other: Self) -> Self {
+                #[cfg(feature = "f32")]
+                {
+                    Self::from(libm::hypotf(self.native, other.native))
+                }
+
+                #[cfg(not(feature = "f32"))]
+                {
+                    Self::from(libm::hypot(self.native, other.native))
+                }
+            }
+
+            pub fn checked_add(self, rhs: Self) -> Result<Self, crate::UnitError> {
+                crate::Quantity::validate(Self::from(self.native + rhs.native))
+            }
+
+            pub fn checked_sub(self, rhs: Self) -> Result<Self, crate::UnitError> {
+                crate::Quantity::validate(Self::from(self.native - rhs.native))
+            }
+
+            pub fn checked_mul<Rhs, Output>(self, rhs: Rhs) -> Result<Output, crate::UnitError>
+            where
+                Self: core::ops::Mul<Rhs, Output = Output>,
+                Output: crate::Quantity,
+            {
+                (self * rhs).validate()
+            }
+
+            pub fn checked_div<Rhs, Output>(self, rhs: Rhs) -> Result<Output, crate::UnitError>
+            where
+                Self: core::ops::Div<Rhs, Output = Output>,
+                Output: crate::Quantity,
+            {
+                (self / rhs).validate()
+            }
+        }
+
+        impl crate::Quantity for #name {
+            const NAME: &'static str = stringify!(#name);
+            const LABEL: &'static str = #label;
+            const ALIASES: &'static [&'static str] = &[#(#aliases),*];
+            const SYMBOL: &'static str = #symbol;
+            const ASCII_SYMBOL: &'static str = #ascii_symbol;
+            const UNIT: &'static [crate::render::Factor] = &[#(#factors),*];
+            const SINGULAR: &'static str = #singular;
+            const PLURAL: &'static str = #plural;
+            const PREFIXABLE: bool = #prefixable;
+
+            fn validate(self) -> Result<Self, crate::UnitError> {
+                if !self.native.is_finite() {
+                    return Err(crate::UnitError::new::<Self>(
+                        self.native,
+                        crate::UnitErrorKind::NonFinite,
+                    ));
+                }
+                #minimum_check
+                Ok(self)
+            }
         }
 
         impl Into<crate::NativeType> for #name {
@@ -207,14 +353,17 @@ fn impl_multiply_divide_scalar_macro(ast: &syn::DeriveInput) -> TokenStream {
     generate.into()
 }
 
-fn get_parameters(ast: &DeriveInput, expect: &'static str) -> Parameters {
+fn find_parameters(ast: &DeriveInput) -> Option<Parameters> {
     let attribute = ast
         .attrs
         .iter()
-        .find(|a| a.path.segments.len() == 1 && a.path.segments[0].ident == "parameters")
-        .expect(expect);
+        .find(|a| a.path.segments.len() == 1 && a.path.segments[0].ident == "parameters")?;
 
-    syn::parse2(attribute.tokens.clone()).expect("Invalid parameters attribute!")
+    Some(syn::parse2(attribute.tokens.clone()).expect("Invalid parameters attribute!"))
+}
+
+fn get_parameters(ast: &DeriveInput, expect: &'static str) -> Parameters {
+    find_parameters(ast).expect(expect)
 }
 
 #[proc_macro_derive(SiMultiply, attributes(parameters))]
@@ -314,6 +463,71 @@ pub fn square_macro_derive(input: TokenStream) -> TokenStream {
             }
         }
 
+        impl #square {
+            pub fn square(&self) -> #name {
+                #name::from(self.native * self.native)
+            }
+        }
+
+        impl crate::Squared for #square {
+            type Output = #name;
+            fn square(&self) -> #name {
+                #square::square(self)
+            }
+        }
+
+        impl crate::SquareRoot for #name {
+            type Output = #square;
+            fn sqrt(&self) -> #square {
+                #name::sqrt(self)
+            }
+        }
+
+    };
+    generate.into()
+}
+
+#[proc_macro_derive(SiCube, attributes(parameters))]
+pub fn cube_macro_derive(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+    let parameters = get_parameters(&ast, "parameters required for deriving SiCube");
+    let name = &ast.ident;
+    let cube = parameters.get_token("cube");
+    let generate = quote::quote! {
+        impl #name {
+            pub fn cbrt(&self) -> #cube {
+                #[cfg(feature = "f32")]
+                {
+                    #cube::from(libm::cbrtf(self.native))
+                }
+
+                #[cfg(not(feature = "f32"))]
+                {
+                    #cube::from(libm::cbrt(self.native))
+                }
+            }
+        }
+
+        impl #cube {
+            pub fn cube(&self) -> #name {
+                #name::from(self.native * self.native * self.native)
+            }
+        }
+
+        impl crate::Cubed for #cube {
+            type Output = #name;
+            fn cube(&self) -> #name {
+                #cube::cube(self)
+            }
+        }
+
+        impl crate::CubeRoot for #name {
+            type Output = #cube;
+            fn cbrt(&self) -> #cube {
+                #name::cbrt(self)
+            }
+        }
+
     };
     generate.into()
 }
@@ -432,400 +646,942 @@ pub fn convert_macro_derive(input: TokenStream) -> TokenStream {
 struct UnitType {
     name: &'static str,
     label: &'static str,
+    symbol: &'static str,
+    singular: &'static str,
+    plural: &'static str,
+    prefixable: bool,
 }
 
 const UNITS: &[UnitType] = &[
     UnitType {
         name: "Scalar",
         label: "scalar",
+        symbol: "",
+        singular: "scalar",
+        plural: "scalar",
+        prefixable: false,
     },
     UnitType {
         name: "DecibelV",
         label: "decibelsV",
+        symbol: "dBV",
+        singular: "decibelV",
+        plural: "decibelsV",
+        prefixable: false,
     },
     UnitType {
         name: "Decibel",
         label: "decibels",
+        symbol: "dB",
+        singular: "decibel",
+        plural: "decibels",
+        prefixable: false,
+    },
+    UnitType {
+        name: "Neper",
+        label: "nepers",
+        symbol: "Np",
+        singular: "neper",
+        plural: "nepers",
+        prefixable: false,
+    },
+    UnitType {
+        name: "Bel",
+        label: "bels",
+        symbol: "B",
+        singular: "bel",
+        plural: "bels",
+        prefixable: false,
+    },
+    UnitType {
+        name: "Octave",
+        label: "octaves",
+        symbol: "oct",
+        singular: "octave",
+        plural: "octaves",
+        prefixable: false,
+    },
+    UnitType {
+        name: "Decade",
+        label: "decades",
+        symbol: "dec",
+        singular: "decade",
+        plural: "decades",
+        prefixable: false,
+    },
+    UnitType {
+        name: "DecibelsPerOctave",
+        label: "decibels/octave",
+        symbol: "dB/oct",
+        singular: "decibel/octave",
+        plural: "decibels/octave",
+        prefixable: false,
+    },
+    UnitType {
+        name: "DecibelsPerDecade",
+        label: "decibels/decade",
+        symbol: "dB/dec",
+        singular: "decibel/decade",
+        plural: "decibels/decade",
+        prefixable: false,
     },
     UnitType {
         name: "Mass",
         label: "kilograms",
+        symbol: "kg",
+        singular: "kilogram",
+        plural: "kilograms",
+        prefixable: false,
     },
     UnitType {
         name: "Time",
         label: "seconds",
+        symbol: "s",
+        singular: "second",
+        plural: "seconds",
+        prefixable: true,
     },
     UnitType {
         name: "ElectricCurrent",
         label: "amps",
+        symbol: "A",
+        singular: "amp",
+        plural: "amps",
+        prefixable: true,
     },
     UnitType {
         name: "ThermodynamicTemperature",
         label: "kelvin",
+        symbol: "K",
+        singular: "kelvin",
+        plural: "kelvin",
+        prefixable: true,
     },
     UnitType {
         name: "AmountOfSubstance",
         label: "moles",
+        symbol: "mol",
+        singular: "mole",
+        plural: "moles",
+        prefixable: true,
     },
     UnitType {
         name: "Length",
         label: "meters",
+        symbol: "m",
+        singular: "meter",
+        plural: "meters",
+        prefixable: true,
     },
     UnitType {
         name: "LengthInverse",
         label: "1/meter",
+        symbol: "1/m",
+        singular: "1/meter",
+        plural: "1/meter",
+        prefixable: false,
     },
     UnitType {
         name: "OrthogonalLength",
         label: "meters",
+        symbol: "m",
+        singular: "meter",
+        plural: "meters",
+        prefixable: true,
     },
     UnitType {
         name: "LuminousIntensity",
         label: "candelas",
+        symbol: "cd",
+        singular: "candela",
+        plural: "candelas",
+        prefixable: true,
     },
     UnitType {
         name: "PlaneAngle",
         label: "radians",
+        symbol: "rad",
+        singular: "radian",
+        plural: "radians",
+        prefixable: true,
     },
     UnitType {
         name: "PlaneAngleInverse",
         label: "1/radians",
+        symbol: "1/rad",
+        singular: "1/radian",
+        plural: "1/radians",
+        prefixable: false,
     },
     UnitType {
         name: "SolidAngle",
         label: "steradians",
+        symbol: "sr",
+        singular: "steradian",
+        plural: "steradians",
+        prefixable: false,
     },
     UnitType {
         name: "Frequency",
         label: "hertz",
+        symbol: "Hz",
+        singular: "hertz",
+        plural: "hertz",
+        prefixable: true,
     },
     UnitType {
         name: "FrequencySquared",
         label: "hertz^2",
+        symbol: "Hz²",
+        singular: "hertz^2",
+        plural: "hertz^2",
+        prefixable: false,
     },
     UnitType {
         name: "Area",
         label: "meters^2",
+        symbol: "m²",
+        singular: "meter^2",
+        plural: "meters^2",
+        prefixable: false,
     },
     UnitType {
         name: "Volume",
         label: "meters^3",
+        symbol: "m³",
+        singular: "meter^3",
+        plural: "meters^3",
+        prefixable: false,
     },
     UnitType {
         name: "Liters",
         label: "liters",
+        symbol: "L",
+        singular: "liter",
+        plural: "liters",
+        prefixable: true,
+    },
+    UnitType {
+        name: "VolumetricFlow",
+        label: "meters^3/second",
+        symbol: "m³/s",
+        singular: "meter^3/second",
+        plural: "meters^3/second",
+        prefixable: false,
     },
     UnitType {
         name: "Velocity",
         label: "meters/second",
+        symbol: "m/s",
+        singular: "meter/second",
+        plural: "meters/second",
+        prefixable: true,
     },
     UnitType {
         name: "VelocitySquared",
         label: "(meters/second)^2",
+        symbol: "(m/s)²",
+        singular: "(meter/second)^2",
+        plural: "(meters/second)^2",
+        prefixable: false,
     },
     UnitType {
         name: "Acceleration",
         label: "meters/second^2",
+        symbol: "m/s²",
+        singular: "meter/second^2",
+        plural: "meters/second^2",
+        prefixable: true,
     },
     UnitType {
         name: "Jerk",
         label: "meters/second^3",
+        symbol: "m/s³",
+        singular: "meter/second^3",
+        plural: "meters/second^3",
+        prefixable: true,
     },
     UnitType {
         name: "Force",
         label: "newtons",
+        symbol: "N",
+        singular: "newton",
+        plural: "newtons",
+        prefixable: true,
     },
     UnitType {
         name: "Pressure",
         label: "pascals",
+        symbol: "Pa",
+        singular: "pascal",
+        plural: "pascals",
+        prefixable: true,
     },
     UnitType {
         name: "Energy",
         label: "joules",
+        symbol: "J",
+        singular: "joule",
+        plural: "joules",
+        prefixable: true,
     },
     UnitType {
         name: "EnergyPerFrequency",
         label: "joules/hertz",
+        symbol: "J/Hz",
+        singular: "joule/hertz",
+        plural: "joules/hertz",
+        prefixable: true,
     },
     UnitType {
         name: "Power",
         label: "watts",
+        symbol: "W",
+        singular: "watt",
+        plural: "watts",
+        prefixable: true,
     },
     UnitType {
         name: "ElectricCharge",
         label: "coulombs",
+        symbol: "C",
+        singular: "coulomb",
+        plural: "coulombs",
+        prefixable: true,
     },
     UnitType {
         name: "ElectricPotential",
         label: "volts",
+        symbol: "V",
+        singular: "volt",
+        plural: "volts",
+        prefixable: true,
     },
     UnitType {
         name: "Capacitance",
         label: "farads",
+        symbol: "F",
+        singular: "farad",
+        plural: "farads",
+        prefixable: true,
     },
     UnitType {
         name: "ElectricResistance",
         label: "ohms",
+        symbol: "Ω",
+        singular: "ohm",
+        plural: "ohms",
+        prefixable: true,
     },
     UnitType {
         name: "ElectricConductance",
         label: "siemens",
+        symbol: "S",
+        singular: "siemens",
+        plural: "siemens",
+        prefixable: true,
     },
     UnitType {
         name: "MagneticFlux",
         label: "webers",
+        symbol: "Wb",
+        singular: "weber",
+        plural: "webers",
+        prefixable: true,
     },
     UnitType {
         name: "MagneticFluxDensity",
         label: "teslas",
+        symbol: "T",
+        singular: "tesla",
+        plural: "teslas",
+        prefixable: true,
     },
     UnitType {
         name: "Inductance",
         label: "henries",
+        symbol: "H",
+        singular: "henry",
+        plural: "henries",
+        prefixable: true,
     },
     UnitType {
         name: "Temperature",
-        label: "celcius",
+        label: "celsius",
+        symbol: "°C",
+        singular: "degree Celsius",
+        plural: "degrees Celsius",
+        prefixable: false,
     },
     UnitType {
         name: "LuminousFlux",
         label: "lumens",
+        symbol: "lm",
+        singular: "lumen",
+        plural: "lumens",
+        prefixable: true,
     },
     UnitType {
         name: "Illuminance",
         label: "lux",
+        symbol: "lx",
+        singular: "lux",
+        plural: "lux",
+        prefixable: true,
     },
     UnitType {
         name: "DynamicViscosity",
         label: "pascals*seconds",
+        symbol: "Pa·s",
+        singular: "pascal*second",
+        plural: "pascals*seconds",
+        prefixable: true,
     },
     UnitType {
         name: "MomentOfForce",
         label: "newtons*meters",
+        symbol: "N·m",
+        singular: "newton*meter",
+        plural: "newtons*meters",
+        prefixable: true,
     },
     UnitType {
         name: "Torque",
         label: "newtons*meters",
+        symbol: "N·m",
+        singular: "newton*meter",
+        plural: "newtons*meters",
+        prefixable: true,
     },
     UnitType {
         name: "AngularVelocity",
         label: "radians/second",
+        symbol: "rad/s",
+        singular: "radian/second",
+        plural: "radians/second",
+        prefixable: true,
     },
     UnitType {
         name: "AngularVelocitySquared",
         label: "(radians/second)^2",
+        symbol: "(rad/s)²",
+        singular: "(radian/second)^2",
+        plural: "(radians/second)^2",
+        prefixable: false,
     },
     UnitType {
         name: "AngularAcceleration",
         label: "radians/second^2",
+        symbol: "rad/s²",
+        singular: "radian/second^2",
+        plural: "radians/second^2",
+        prefixable: true,
     },
     UnitType {
         name: "SurfaceTension",
         label: "newtons/meter",
+        symbol: "N/m",
+        singular: "newton/meter",
+        plural: "newtons/meter",
+        prefixable: true,
     },
     UnitType {
         name: "HeatFluxDensity",
         label: "watts/meter^2",
+        symbol: "W/m²",
+        singular: "watt/meter^2",
+        plural: "watts/meter^2",
+        prefixable: true,
     },
     UnitType {
         name: "HeatCapacity",
         label: "joules/kelvin",
+        symbol: "J/K",
+        singular: "joule/kelvin",
+        plural: "joules/kelvin",
+        prefixable: true,
     },
     UnitType {
         name: "SpecificHeatCapacity",
         label: "joules/(kilogram*kelvin)",
+        symbol: "J/(kg·K)",
+        singular: "joule/(kilogram*kelvin)",
+        plural: "joules/(kilogram*kelvin)",
+        prefixable: true,
     },
     UnitType {
         name: "SpecificEnergy",
         label: "joules/kilogram",
+        symbol: "J/kg",
+        singular: "joule/kilogram",
+        plural: "joules/kilogram",
+        prefixable: true,
     },
     UnitType {
         name: "ThermalConductivity",
         label: "watts/(meter*kelvin)",
+        symbol: "W/(m·K)",
+        singular: "watt/(meter*kelvin)",
+        plural: "watts/(meter*kelvin)",
+        prefixable: true,
     },
     UnitType {
         name: "EnergyDensity",
         label: "joules/meter^3",
+        symbol: "J/m³",
+        singular: "joule/meter^3",
+        plural: "joules/meter^3",
+        prefixable: true,
     },
     UnitType {
         name: "ElectricFieldStrength",
         label: "volts/meter",
+        symbol: "V/m",
+        singular: "volt/meter",
+        plural: "volts/meter",
+        prefixable: true,
     },
     UnitType {
         name: "ElectricChargeDensity",
         label: "coulombs/meter^3",
+        symbol: "C/m³",
+        singular: "coulomb/meter^3",
+        plural: "coulombs/meter^3",
+        prefixable: true,
     },
     UnitType {
         name: "ElectricFluxDensity",
         label: "coulombs/meter^2",
+        symbol: "C/m²",
+        singular: "coulomb/meter^2",
+        plural: "coulombs/meter^2",
+        prefixable: true,
     },
     UnitType {
         name: "Permittivity",
         label: "farads/meter",
+        symbol: "F/m",
+        singular: "farad/meter",
+        plural: "farads/meter",
+        prefixable: true,
     },
     UnitType {
         name: "Permeability",
         label: "henries/meter",
+        symbol: "H/m",
+        singular: "henry/meter",
+        plural: "henries/meter",
+        prefixable: true,
     },
     UnitType {
         name: "MolarEnergy",
         label: "joules/mole",
+        symbol: "J/mol",
+        singular: "joule/mole",
+        plural: "joules/mole",
+        prefixable: true,
     },
     UnitType {
         name: "MolarHeatCapacity",
         label: "joules/(mole*kelvin)",
+        symbol: "J/(mol·K)",
+        singular: "joule/(mole*kelvin)",
+        plural: "joules/(mole*kelvin)",
+        prefixable: true,
     },
     UnitType {
         name: "Radiance",
         label: "watts/(meter^2*steradian)",
+        symbol: "W/(m²·sr)",
+        singular: "watt/(meter^2*steradian)",
+        plural: "watts/(meter^2*steradian)",
+        prefixable: true,
     },
     UnitType {
         name: "MassThermodynamicTemperature",
         label: "kilograms*kelvin",
+        symbol: "kg·K",
+        singular: "kilogram*kelvin",
+        plural: "kilograms*kelvin",
+        prefixable: false,
     },
     UnitType {
         name: "LengthThermodynamicTemperature",
         label: "meters*kelvin",
+        symbol: "m·K",
+        singular: "meter*kelvin",
+        plural: "meters*kelvin",
+        prefixable: true,
     },
     UnitType {
         name: "AmountOfSubstanceThermodynamicTemperature",
         label: "moles*kelvin",
+        symbol: "mol·K",
+        singular: "mole*kelvin",
+        plural: "moles*kelvin",
+        prefixable: true,
     },
     UnitType {
         name: "AreaSolidAngle",
         label: "meters^2/steradian",
+        symbol: "m²/sr",
+        singular: "meter^2/steradian",
+        plural: "meters^2/steradian",
+        prefixable: false,
     },
     UnitType {
         name: "PerAmountOfSubstance",
         label: "1/mole",
+        symbol: "1/mol",
+        singular: "1/mole",
+        plural: "1/mole",
+        prefixable: false,
     },
     UnitType {
         name: "EnergyPerFrequency",
         label: "joules/hertz",
+        symbol: "J/Hz",
+        singular: "joule/hertz",
+        plural: "joules/hertz",
+        prefixable: true,
     },
     UnitType {
         name: "MassDensity",
         label: "kilograms/meter^3",
+        symbol: "kg/m³",
+        singular: "kilogram/meter^3",
+        plural: "kilograms/meter^3",
+        prefixable: false,
     },
     // Imperial units
     UnitType {
         name: "Feet",
         label: "feet",
+        symbol: "ft",
+        singular: "foot",
+        plural: "feet",
+        prefixable: false,
     },
     UnitType {
         name: "Yard",
         label: "yards",
+        symbol: "yd",
+        singular: "yard",
+        plural: "yards",
+        prefixable: false,
     },
     UnitType {
         name: "Inch",
         label: "inches",
+        symbol: "in",
+        singular: "inch",
+        plural: "inches",
+        prefixable: false,
     },
     UnitType {
         name: "Miles",
         label: "miles",
+        symbol: "mi",
+        singular: "mile",
+        plural: "miles",
+        prefixable: false,
     },
     UnitType {
         name: "Acres",
         label: "acres",
+        symbol: "ac",
+        singular: "acre",
+        plural: "acres",
+        prefixable: false,
     },
     UnitType {
         name: "SquareMiles",
         label: "squaremiles",
+        symbol: "mi²",
+        singular: "square mile",
+        plural: "square miles",
+        prefixable: false,
     },
     UnitType {
         name: "Pints",
         label: "pt",
+        symbol: "pt",
+        singular: "pint",
+        plural: "pints",
+        prefixable: false,
     },
     UnitType {
         name: "Quarts",
         label: "qt",
+        symbol: "qt",
+        singular: "quart",
+        plural: "quarts",
+        prefixable: false,
     },
     UnitType {
         name: "Gallons",
         label: "gal",
+        symbol: "gal",
+        singular: "gallon",
+        plural: "gallons",
+        prefixable: false,
     },
     UnitType {
         name: "Degrees",
         label: "degrees",
+        symbol: "°",
+        singular: "degree",
+        plural: "degrees",
+        prefixable: false,
     },
     UnitType {
         name: "DegreesPerSecond",
         label: "degrees/second",
+        symbol: "°/s",
+        singular: "degree/second",
+        plural: "degrees/second",
+        prefixable: false,
     },
     UnitType {
         name: "DegreesPerSecondSquared",
         label: "degrees/second^2",
+        symbol: "°/s²",
+        singular: "degree/second^2",
+        plural: "degrees/second^2",
+        prefixable: false,
     },
     UnitType {
         name: "DegreesFahrenheit",
         label: "degreesF",
+        symbol: "°F",
+        singular: "degree Fahrenheit",
+        plural: "degrees Fahrenheit",
+        prefixable: false,
     },
     UnitType {
         name: "DegreesRankine",
         label: "degreesR",
+        symbol: "°R",
+        singular: "degree Rankine",
+        plural: "degrees Rankine",
+        prefixable: false,
     },
     UnitType {
         name: "Revolutions",
         label: "revolutions",
+        symbol: "rev",
+        singular: "revolution",
+        plural: "revolutions",
+        prefixable: false,
     },
     UnitType {
         name: "RevolutionsPerMinute",
         label: "rpm",
+        symbol: "rpm",
+        singular: "revolution/minute",
+        plural: "revolutions/minute",
+        prefixable: false,
     },
     UnitType {
         name: "NauticalMiles",
         label: "nauticalmiles",
+        symbol: "nmi",
+        singular: "nautical mile",
+        plural: "nautical miles",
+        prefixable: false,
     },
     UnitType {
         name: "Knots",
         label: "knots",
+        symbol: "kn",
+        singular: "knot",
+        plural: "knots",
+        prefixable: false,
     },
     UnitType {
         name: "FeetPerSecond",
         label: "feet/second",
+        symbol: "ft/s",
+        singular: "foot/second",
+        plural: "feet/second",
+        prefixable: false,
     },
     UnitType {
         name: "FeetPerSecondSquared",
         label: "feet/second^2",
+        symbol: "ft/s²",
+        singular: "foot/second^2",
+        plural: "feet/second^2",
+        prefixable: false,
     },
     UnitType {
         name: "FeetPerMinute",
         label: "feet/minute",
+        symbol: "ft/min",
+        singular: "foot/minute",
+        plural: "feet/minute",
+        prefixable: false,
     },
     UnitType {
         name: "G",
         label: "g",
+        symbol: "g",
+        singular: "g",
+        plural: "g",
+        prefixable: false,
     },
     UnitType {
         name: "PoundsForce",
         label: "lbsforce",
+        symbol: "lbf",
+        singular: "pound-force",
+        plural: "pounds-force",
+        prefixable: false,
     },
     UnitType {
         name: "Pounds",
         label: "lbs",
+        symbol: "lb",
+        singular: "pound",
+        plural: "pounds",
+        prefixable: false,
     },
     UnitType {
         name: "Ounces",
         label: "oz",
+        symbol: "oz",
+        singular: "ounce",
+        plural: "ounces",
+        prefixable: false,
     },
     UnitType {
         name: "PoundsPerSquareInch",
         label: "psi",
+        symbol: "psi",
+        singular: "pound/square inch",
+        plural: "pounds/square inch",
+        prefixable: false,
     },
     UnitType {
         name: "PoundsPerSquareFoot",
         label: "psf",
+        symbol: "psf",
+        singular: "pound/square foot",
+        plural: "pounds/square foot",
+        prefixable: false,
     },
     UnitType {
         name: "InchesMercury",
         label: "inHg",
+        symbol: "inHg",
+        singular: "inch of mercury",
+        plural: "inches of mercury",
+        prefixable: false,
     },
     UnitType {
         name: "FootPounds",
         label: "ftlbs",
+        symbol: "ft·lbf",
+        singular: "foot-pound",
+        plural: "foot-pounds",
+        prefixable: false,
     },
 ];
 
+/// Spells `symbol` without unicode (e.g. `"degC"` for `"°C"`)
+fn ascii_symbol(symbol: &str) -> String {
+    [
+        ("°C", "degC"),
+        ("°F", "degF"),
+        ("°R", "degR"),
+        ("°", "deg"),
+        ("Ω", "Ohm"),
+        ("²", "^2"),
+        ("³", "^3"),
+        ("·", "*"),
+    ]
+    .iter()
+    .fold(symbol.to_string(), |ascii, (unicode, replacement)| {
+        ascii.replace(unicode, replacement)
+    })
+}
+
+/// Splits a symbol such as `"J/(kg·K)"` into symbols and powers (`J`, `kg^-1`, `K^-1`)
+fn unit_factors(symbol: &str) -> Vec<(String, i8)> {
+    type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+    fn power(chars: &mut Chars) -> i8 {
+        match chars.peek() {
+            Some('²') => {
+                chars.next();
+                2
+            }
+            Some('³') => {
+                chars.next();
+                3
+            }
+            _ => 1,
+        }
+    }
+
+    fn product(chars: &mut Chars, factors: &mut Vec<(String, i8)>) {
+        let mut sign = 1;
+        loop {
+            match chars.peek() {
+                None | Some(')') => return,
+                Some('·') => {
+                    chars.next();
+                    sign = 1;
+                }
+                Some('/') => {
+                    chars.next();
+                    sign = -1;
+                }
+                Some('(') => {
+                    chars.next();
+                    let mut group = Vec::new();
+                    product(chars, &mut group);
+                    chars.next();
+                    let power = power(chars) * sign;
+                    factors.extend(group.into_iter().map(|(symbol, p)| (symbol, p * power)));
+                }
+                Some(_) => {
+                    let mut atom = String::new();
+                    while let Some(c) = chars.next_if(|c| !"·/()²³".contains(*c)) {
+                        atom.push(c);
+                    }
+                    let power = power(chars) * sign;
+                    // the 1 of 1/m
+                    if atom != "1" {
+                        factors.push((atom, power));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut factors = Vec::new();
+    product(&mut symbol.chars().peekable(), &mut factors);
+    factors
+}
+
+/// Other names each quantity accepts when parsing and deserializing
+///
+/// When a label in `UNITS` is changed, add the old one here so that stored
+/// data written with it still loads.
+const ALIASES: &[(&str, &str)] = &[
+    // the label before the spelling was corrected
+    ("Temperature", "celcius"),
+    ("Length", "metre"),
+    ("Length", "metres"),
+    ("Liters", "litre"),
+    ("Liters", "litres"),
+    ("ElectricCurrent", "ampere"),
+    ("ElectricCurrent", "amperes"),
+    ("DegreesFahrenheit", "fahrenheit"),
+    ("RevolutionsPerMinute", "revolutions per minute"),
+];
+
 fn find_unit(name: String) -> &'static UnitType {
     for unit in UNITS {
         if name == unit.name {
@@ -850,69 +1606,61 @@ fn impl_display_macro(ast: &syn::DeriveInput) -> TokenStream {
         }
       }
 
-      #[cfg(feature = "std")]
+      #[cfg(feature = "serde")]
       impl serde::Serialize for #name {
-          fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-          where
-              S: serde::Serializer,
-          {
-              // Format however you want
-              let s = std::format!("{}_{}", self.native, #label);
-              serializer.serialize_str(&s)
+          fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+              crate::representation::string::serialize(self, serializer)
           }
       }
 
-      #[cfg(feature = "std")]
+      #[cfg(feature = "serde")]
       impl<'de> serde::Deserialize<'de> for #name {
-          fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-          where
-              D: serde::Deserializer<'de>,
-          {
-              struct UnitsVisitor;
-
-              impl<'de> serde::de::Visitor<'de> for UnitsVisitor {
-                  type Value = #name;
-
-                  fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                      f.write_str(format!("a string like `10.0_{}`", #label).as_str())
-                  }
-
-                  fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-                  where
-                      E: serde::de::Error,
-                  {
-                      // Expect format "<value>_meters"
-                      if let Some((value_str, #label)) = v.rsplit_once('_') {
-                          let native: crate::NativeType = value_str
-                              .parse()
-                              .map_err(|_| E::custom(format!("invalid float in {}", #label).as_str()))?;
-                          return Ok(#name { native });
-                      }
-
-                      Err(E::custom(format!("expected format `<float>_{}`", #label).as_str()))
-                  }
-              }
-
-              deserializer.deserialize_str(UnitsVisitor)
+          fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+              crate::representation::string::deserialize(deserializer)
           }
       }
 
-      #[cfg(feature = "std")]
-      impl std::fmt::Display for #name {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-          write!(f, "{} {}", self.native, #label)
+      impl core::str::FromStr for #name {
+        type Err = crate::parse::ParseError;
+
+        fn from_str(text: &str) -> Result<Self, Self::Err> {
+          crate::parse::parse(text)
         }
       }
 
-      #[cfg(feature = "std")]
-      impl std::fmt::Debug for #name {
-        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::result::Result<(), std::fmt::Error> {
+      impl core::fmt::Display for #name {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+          core::fmt::Display::fmt(&self.format(), f)
+        }
+      }
+
+      impl core::fmt::Debug for #name {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
           f.debug_struct(stringify!(#name))
-              .field("value", &self.to_string())
+              .field("value", &self.native)
               .field("label", &#label)
               .finish()
         }
       }
+
+      #[cfg(feature = "ufmt")]
+      impl ufmt::uDisplay for #name {
+        fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+          crate::format::write_ufmt::<Self, W>(self.native, f)
+        }
+      }
+
+      #[cfg(feature = "ufmt")]
+      impl ufmt::uDebug for #name {
+        fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+          f.write_str(stringify!(#name))?;
+          f.write_str(" { value: ")?;
+          crate::format::write_ufmt_exact(self.native, f)?;
+          f.write_str(", label: \"")?;
+          f.write_str(#label)?;
+          f.write_str("\" }")
+        }
+      }
     };
     generate.into()
 }
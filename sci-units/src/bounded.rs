@@ -0,0 +1,323 @@
+use core::marker::PhantomData;
+
+use crate::{NativeType, Quantity, UnitError};
+
+/// Describes the inclusive range of a `Bounded` quantity
+///
+/// `MINIMUM` must not be greater than `MAXIMUM` (and must be less than it for
+/// wrapping); constructors panic otherwise, as quantities can't be compared in
+/// a `const`.
+pub trait Limits<T: Quantity> {
+    const MINIMUM: T;
+    const MAXIMUM: T;
+}
+
+/// A quantity that is guaranteed to be within `L::MINIMUM..=L::MAXIMUM`
+///
+/// The `wrapping_*` functions wrap into the half-open `L::MINIMUM..L::MAXIMUM`
+/// instead, so `L::MAXIMUM` wraps to `L::MINIMUM` (e.g. 360° to 0°).
+pub struct Bounded<T: Quantity, L: Limits<T>> {
+    value: T,
+    limits: PhantomData<L>,
+}
+
+impl<T: Quantity + PartialOrd, L: Limits<T>> Bounded<T, L> {
+    const fn new_unchecked(value: T) -> Self {
+        Self {
+            value,
+            limits: PhantomData,
+        }
+    }
+
+    fn out_of_range(value: T) -> UnitError {
+        UnitError::out_of_range::<T>(value.into(), L::MINIMUM.into(), L::MAXIMUM.into())
+    }
+
+    fn check_limits() {
+        assert!(
+            L::MINIMUM <= L::MAXIMUM,
+            "the minimum of a Bounded quantity is greater than its maximum"
+        );
+    }
+
+    /// Fails with `OutOfRange` outside of the limits
+    ///
+    /// Panics if `L::MINIMUM` is greater than `L::MAXIMUM`.
+    pub fn try_new(value: T) -> Result<Self, UnitError> {
+        Self::check_limits();
+        let value = value.validate()?;
+        if value < L::MINIMUM || value > L::MAXIMUM {
+            return Err(Self::out_of_range(value));
+        }
+        Ok(Self::new_unchecked(value))
+    }
+
+    /// Clamps `value` to the limits
+    ///
+    /// Only fails if `value` isn't finite. Panics if `L::MINIMUM` is greater
+    /// than `L::MAXIMUM`.
+    pub fn new_saturating(value: T) -> Result<Self, UnitError> {
+        Self::check_limits();
+        let value = value.validate()?;
+        if value < L::MINIMUM {
+            Ok(Self::new_unchecked(L::MINIMUM))
+        } else if value > L::MAXIMUM {
+            Ok(Self::new_unchecked(L::MAXIMUM))
+        } else {
+            Ok(Self::new_unchecked(value))
+        }
+    }
+
+    /// Wraps `value` into `L::MINIMUM..L::MAXIMUM`, excluding the maximum
+    /// (e.g. for headings)
+    ///
+    /// Only fails if `value` isn't finite. Panics unless `L::MINIMUM` is less
+    /// than `L::MAXIMUM`, as there is nothing to wrap into.
+    pub fn new_wrapping(value: T) -> Result<Self, UnitError> {
+        assert!(
+            L::MINIMUM < L::MAXIMUM,
+            "a wrapping Bounded quantity needs a minimum less than its maximum"
+        );
+        let value: NativeType = value.validate()?.into();
+        let minimum: NativeType = L::MINIMUM.into();
+        let span = L::MAXIMUM.into() - minimum;
+
+        #[cfg(feature = "f32")]
+        let mut offset = libm::fmodf(value - minimum, span);
+        #[cfg(not(feature = "f32"))]
+        let mut offset = libm::fmod(value - minimum, span);
+
+        if offset < 0.0 {
+            offset += span;
+        }
+        let mut wrapped = minimum + offset;
+        // rounding can push a tiny negative offset up to exactly the maximum
+        if offset >= span || wrapped >= L::MAXIMUM.into() {
+            wrapped = minimum;
+        }
+        Ok(Self::new_unchecked(T::from(wrapped)))
+    }
+
+    pub fn minimum() -> T {
+        L::MINIMUM
+    }
+
+    pub fn maximum() -> T {
+        L::MAXIMUM
+    }
+
+    pub fn get(&self) -> T {
+        self.value
+    }
+
+    pub fn checked_add(self, rhs: T) -> Result<Self, UnitError>
+    where
+        T: core::ops::Add<Output = T>,
+    {
+        Self::try_new(self.value + rhs)
+    }
+
+    pub fn checked_sub(self, rhs: T) -> Result<Self, UnitError>
+    where
+        T: core::ops::Sub<Output = T>,
+    {
+        Self::try_new(self.value - rhs)
+    }
+
+    /// Only fails if the sum isn't finite
+    pub fn saturating_add(self, rhs: T) -> Result<Self, UnitError>
+    where
+        T: core::ops::Add<Output = T>,
+    {
+        Self::new_saturating(self.value + rhs)
+    }
+
+    /// Only fails if the difference isn't finite
+    pub fn saturating_sub(self, rhs: T) -> Result<Self, UnitError>
+    where
+        T: core::ops::Sub<Output = T>,
+    {
+        Self::new_saturating(self.value - rhs)
+    }
+
+    /// Only fails if the sum isn't finite
+    pub fn wrapping_add(self, rhs: T) -> Result<Self, UnitError>
+    where
+        T: core::ops::Add<Output = T>,
+    {
+        Self::new_wrapping(self.value + rhs)
+    }
+
+    /// Only fails if the difference isn't finite
+    pub fn wrapping_sub(self, rhs: T) -> Result<Self, UnitError>
+    where
+        T: core::ops::Sub<Output = T>,
+    {
+        Self::new_wrapping(self.value - rhs)
+    }
+}
+
+impl<T: Quantity, L: Limits<T>> Copy for Bounded<T, L> {}
+
+impl<T: Quantity, L: Limits<T>> Clone for Bounded<T, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Quantity + PartialEq, L: Limits<T>> PartialEq for Bounded<T, L> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.value == rhs.value
+    }
+}
+
+impl<T: Quantity + PartialOrd, L: Limits<T>> PartialOrd for Bounded<T, L> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Quantity + core::fmt::Display, L: Limits<T>> core::fmt::Display for Bounded<T, L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: Quantity + core::fmt::Debug, L: Limits<T>> core::fmt::Debug for Bounded<T, L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Bounded")
+            .field("value", &self.value)
+            .field("minimum", &L::MINIMUM.into())
+            .field("maximum", &L::MAXIMUM.into())
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::imperial::{Degrees, PoundsPerSquareInch};
+    use crate::{ElectricCurrent, UnitErrorKind};
+
+    struct MotorCurrent;
+
+    impl Limits<ElectricCurrent> for MotorCurrent {
+        const MINIMUM: ElectricCurrent = ElectricCurrent::new(0.0);
+        const MAXIMUM: ElectricCurrent = ElectricCurrent::new(12.0);
+    }
+
+    struct TankPressure;
+
+    impl Limits<PoundsPerSquareInch> for TankPressure {
+        const MINIMUM: PoundsPerSquareInch = PoundsPerSquareInch::new(0.0);
+        const MAXIMUM: PoundsPerSquareInch = PoundsPerSquareInch::new(300.0);
+    }
+
+    struct Heading;
+
+    impl Limits<Degrees> for Heading {
+        const MINIMUM: Degrees = Degrees::new(0.0);
+        const MAXIMUM: Degrees = Degrees::new(360.0);
+    }
+
+    #[test]
+    fn bounded_construction() {
+        type Current = Bounded<ElectricCurrent, MotorCurrent>;
+
+        let current = Current::try_new(ElectricCurrent::new(5.0)).unwrap();
+        assert_eq!(current.get(), ElectricCurrent::new(5.0));
+
+        let error = Current::try_new(ElectricCurrent::new(13.0)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "ElectricCurrent: 13 amps is out of range [0, 12] amps"
+        );
+        assert!(Current::try_new(ElectricCurrent::new(NativeType::NAN)).is_err());
+
+        let error = current.checked_add(ElectricCurrent::new(10.0)).unwrap_err();
+        assert_eq!(error.label(), "amps");
+
+        let pressure =
+            Bounded::<PoundsPerSquareInch, TankPressure>::try_new(PoundsPerSquareInch::new(-1.0));
+        assert!(pressure.is_err());
+    }
+
+    #[test]
+    fn bounded_arithmetic() {
+        type Current = Bounded<ElectricCurrent, MotorCurrent>;
+        type Angle = Bounded<Degrees, Heading>;
+
+        let current = Current::try_new(ElectricCurrent::new(5.0)).unwrap();
+        assert_eq!(
+            current.saturating_add(ElectricCurrent::new(10.0)),
+            Ok(Current::try_new(ElectricCurrent::new(12.0)).unwrap())
+        );
+        let minimum: NativeType = current
+            .saturating_sub(ElectricCurrent::new(10.0))
+            .unwrap()
+            .get()
+            .into();
+        assert_eq!(minimum, 0.0);
+
+        let heading = Angle::try_new(Degrees::new(350.0)).unwrap();
+        assert_eq!(
+            heading.wrapping_add(Degrees::new(20.0)).unwrap().get(),
+            Degrees::new(10.0)
+        );
+        assert_eq!(
+            heading.wrapping_sub(Degrees::new(700.0)).unwrap().get(),
+            Degrees::new(10.0)
+        );
+
+        // -1e-20 + 360 rounds to 360, which is outside of 0..360
+        let wrapped: NativeType = Angle::new_wrapping(Degrees::new(-1e-20))
+            .unwrap()
+            .get()
+            .into();
+        assert_eq!(wrapped, 0.0);
+        let wrapped: NativeType = Angle::new_wrapping(Degrees::new(360.0))
+            .unwrap()
+            .get()
+            .into();
+        assert_eq!(wrapped, 0.0);
+    }
+
+    struct Fixed;
+
+    impl Limits<Degrees> for Fixed {
+        const MINIMUM: Degrees = Degrees::new(90.0);
+        const MAXIMUM: Degrees = Degrees::new(90.0);
+    }
+
+    struct Backwards;
+
+    impl Limits<ElectricCurrent> for Backwards {
+        const MINIMUM: ElectricCurrent = ElectricCurrent::new(12.0);
+        const MAXIMUM: ElectricCurrent = ElectricCurrent::new(0.0);
+    }
+
+    #[test]
+    fn bounded_limits() {
+        let error = Bounded::<ElectricCurrent, MotorCurrent>::try_new(ElectricCurrent::new(13.0))
+            .unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::OutOfRange);
+        assert_eq!(error.limits(), Some((0.0, 12.0)));
+
+        // a single value is a valid range, but not one that can be wrapped into
+        let fixed = Bounded::<Degrees, Fixed>::new_saturating(Degrees::new(100.0)).unwrap();
+        assert_eq!(fixed.get(), Degrees::new(90.0));
+        assert!(
+            std::panic::catch_unwind(|| Bounded::<Degrees, Fixed>::new_wrapping(Degrees::new(
+                100.0
+            )))
+            .is_err()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than its maximum")]
+    fn backwards_limits() {
+        let _ = Bounded::<ElectricCurrent, Backwards>::try_new(ElectricCurrent::new(5.0));
+    }
+}
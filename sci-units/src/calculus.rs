@@ -0,0 +1,253 @@
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::{NativeType, Quantity, Time, UnitError, UnitErrorKind};
+
+/// Streaming trapezoidal integrator
+pub struct Integrator<T, Y> {
+    previous: Option<(Time, T)>,
+    total: Y,
+}
+
+impl<T, Y> Integrator<T, Y>
+where
+    T: Quantity + Add<Output = T> + Mul<Time, Output = Y>,
+    Y: Quantity + Add<Output = Y>,
+{
+    pub fn new() -> Self {
+        Self {
+            previous: None,
+            total: Y::from(0.0),
+        }
+    }
+
+    /// Adds a sample and returns the integral since the first sample
+    pub fn push(&mut self, time: Time, value: T) -> Y {
+        if let Some((previous_time, previous_value)) = self.previous {
+            let area: NativeType = ((previous_value + value) * (time - previous_time)).into();
+            self.total = self.total + Y::from(area * 0.5);
+        }
+        self.previous = Some((time, value));
+        self.total
+    }
+
+    pub fn total(&self) -> Y {
+        self.total
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<T, Y> Default for Integrator<T, Y>
+where
+    T: Quantity + Add<Output = T> + Mul<Time, Output = Y>,
+    Y: Quantity + Add<Output = Y>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming backward-difference differentiator
+pub struct Differentiator<T, D> {
+    previous: Option<(Time, T)>,
+    derivative: PhantomData<D>,
+}
+
+impl<T, D> Differentiator<T, D>
+where
+    T: Quantity + Sub<Output = T> + Div<Time, Output = D>,
+    D: Quantity,
+{
+    pub fn new() -> Self {
+        Self {
+            previous: None,
+            derivative: PhantomData,
+        }
+    }
+
+    /// Adds a sample and returns the rate of change since the previous sample
+    pub fn push(&mut self, time: Time, value: T) -> Option<D> {
+        let result = self.previous.map(|(previous_time, previous_value)| {
+            (value - previous_value) / (time - previous_time)
+        });
+        self.previous = Some((time, value));
+        result
+    }
+
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl<T, D> Default for Differentiator<T, D>
+where
+    T: Quantity + Sub<Output = T> + Div<Time, Output = D>,
+    D: Quantity,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Integrates samples using the trapezoidal rule (samples may be unevenly spaced)
+pub fn trapezoid<T, Y>(samples: impl IntoIterator<Item = (Time, T)>) -> Y
+where
+    T: Quantity + Add<Output = T> + Mul<Time, Output = Y>,
+    Y: Quantity + Add<Output = Y>,
+{
+    let mut integrator = Integrator::new();
+    for (time, value) in samples {
+        integrator.push(time, value);
+    }
+    integrator.total()
+}
+
+/// How far an interval may be from the mean for `simpson` to treat the
+/// samples as evenly spaced, as a fraction of the mean
+const SPACING_TOLERANCE: NativeType = 1e-3;
+
+/// Integrates evenly spaced samples using Simpson's rule
+///
+/// If there is an even number of samples, the last interval is integrated
+/// with the trapezoidal rule. Fails with `UnevenSpacing` if an interval
+/// differs from the mean by more than 0.1%; use `trapezoid` for those.
+pub fn simpson<T, Y>(samples: &[(Time, T)]) -> Result<Y, UnitError>
+where
+    T: Quantity + Add<Output = T> + Mul<Time, Output = Y>,
+    Y: Quantity + Add<Output = Y>,
+{
+    if samples.len() < 2 {
+        return Ok(Y::from(0.0));
+    }
+
+    let last = samples.len() - 1;
+    let period = Time::new((samples[last].0.native - samples[0].0.native) / last as NativeType);
+    for pair in samples.windows(2) {
+        let interval = pair[1].0 - pair[0].0;
+        if (interval.native - period.native).abs() > period.native.abs() * SPACING_TOLERANCE {
+            return Err(UnitError::new::<Time>(
+                interval.native,
+                UnitErrorKind::UnevenSpacing,
+            ));
+        }
+    }
+
+    let intervals = last & !1;
+    let mut sum: NativeType = 0.0;
+    for (index, (_, value)) in samples[..=intervals].iter().enumerate() {
+        let weight = if index == 0 || index == intervals {
+            1.0
+        } else if index % 2 == 1 {
+            4.0
+        } else {
+            2.0
+        };
+        sum += weight * (*value).into();
+    }
+    let mut total: NativeType = (T::from(sum) * period).into() / 3.0;
+
+    if intervals < last {
+        let tail = (samples[intervals].1 + samples[last].1) * period;
+        total += tail.into() * 0.5;
+    }
+    Ok(Y::from(total))
+}
+
+/// Differentiates samples (which may be unevenly spaced)
+///
+/// Interior points use a central difference and the end points use a one-sided
+/// difference so that the output has one value per sample.
+pub fn derivative<T, D>(samples: &[(Time, T)]) -> impl Iterator<Item = (Time, D)> + '_
+where
+    T: Quantity + Sub<Output = T> + Div<Time, Output = D>,
+    D: Quantity,
+{
+    let last = samples.len().saturating_sub(1);
+    (0..samples.len())
+        .filter(move |_| last > 0)
+        .map(move |index| {
+            let (start_time, start) = samples[index.saturating_sub(1)];
+            let (end_time, end) = samples[(index + 1).min(last)];
+            (samples[index].0, (end - start) / (end_time - start_time))
+        })
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Acceleration, ElectricCharge, ElectricCurrent, Length, Velocity};
+
+    fn ramp(count: usize) -> std::vec::Vec<(Time, Velocity)> {
+        (0..count)
+            .map(|index| {
+                let time = index as NativeType * 0.5;
+                (Time::new(time), Velocity::new(2.0 * time + 1.0))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn integrate_samples() {
+        let distance: Length = trapezoid(ramp(5));
+        assert_eq!(distance, Length::new(6.0));
+
+        let charge: ElectricCharge = trapezoid([
+            (Time::new(1.0), ElectricCurrent::new(3.0)),
+            (Time::new(3.0), ElectricCurrent::new(3.0)),
+        ]);
+        assert_eq!(charge, ElectricCharge::new(6.0));
+
+        let cubic: std::vec::Vec<(Time, Velocity)> = (0..5)
+            .map(|index| {
+                let time = index as NativeType * 0.5;
+                (Time::new(time), Velocity::new(time * time * time))
+            })
+            .collect();
+        let distance: Length = simpson(&cubic).unwrap();
+        assert_eq!(distance, Length::new(4.0));
+        let distance: Length = simpson(&cubic[..4]).unwrap();
+        assert_eq!(distance, Length::new(1.34375));
+
+        let mut uneven = cubic.clone();
+        uneven[2].0 = Time::new(1.2);
+        let error = simpson::<Velocity, Length>(&uneven).unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::UnevenSpacing);
+    }
+
+    #[test]
+    fn streaming_integrator() {
+        let mut integrator = Integrator::<Velocity, Length>::new();
+        let totals: std::vec::Vec<Length> = ramp(3)
+            .into_iter()
+            .map(|(time, value)| integrator.push(time, value))
+            .collect();
+        assert_eq!(totals[1], Length::new(0.75));
+        assert_eq!(totals[2], Length::new(2.0));
+        integrator.reset();
+        assert_eq!(integrator.total(), Length::new(0.0));
+    }
+
+    #[test]
+    fn differentiate_samples() {
+        let acceleration: std::vec::Vec<(Time, Acceleration)> = derivative(&ramp(4)).collect();
+        assert_eq!(acceleration.len(), 4);
+        for (_, value) in acceleration {
+            assert_eq!(value, Acceleration::new(2.0));
+        }
+
+        let mut differentiator = Differentiator::<Length, Velocity>::new();
+        assert!(
+            differentiator
+                .push(Time::new(1.0), Length::new(1.0))
+                .is_none()
+        );
+        assert_eq!(
+            differentiator.push(Time::new(3.0), Length::new(5.0)),
+            Some(Velocity::new(2.0))
+        );
+    }
+}
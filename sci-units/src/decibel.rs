@@ -0,0 +1,905 @@
+use bincode::{Decode, Encode};
+use sci_units_proc_macro::{SiAddSubtract, SiConvert, SiDisplay, SiMultiplyDivideScalar};
+
+use crate::{
+    ElectricPotential, Frequency, NativeType, Power, Pressure, Quantity, Scalar, UnitError,
+    UnitErrorKind,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecibelType {
+    Power,
+    Signal,
+}
+
+impl From<DecibelType> for NativeType {
+    fn from(val: DecibelType) -> Self {
+        match val {
+            DecibelType::Power => 10.0,
+            DecibelType::Signal => 20.0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Decode, Encode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decibel<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>> {
+    value: NativeType, //dB ratio value
+    reference: UnitType,
+    multiplier: DecibelType,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    convention: Option<DecibelReference>,
+}
+
+impl<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>>
+    Decibel<UnitType>
+{
+    pub fn new_from_raw(value: UnitType, reference: UnitType, multiplier: DecibelType) -> Self {
+        let ratio: NativeType = value.into() / reference.into();
+        let mult: NativeType = multiplier.into();
+
+        #[cfg(feature = "f32")]
+        let value = mult * libm::log10f(ratio);
+        #[cfg(not(feature = "f32"))]
+        let value = mult * libm::log10(ratio);
+
+        Self {
+            value,
+            reference,
+            multiplier,
+            convention: None,
+        }
+    }
+
+    /// Like `new_from_raw()` but fails if `value / reference` has no logarithm
+    pub fn try_new_from_raw(
+        value: UnitType,
+        reference: UnitType,
+        multiplier: DecibelType,
+    ) -> Result<Self, UnitError> {
+        let ratio: NativeType = value.into() / reference.into();
+        if ratio < 0.0 {
+            return Err(UnitError::new_with_label(
+                "Decibel",
+                "decibels",
+                ratio,
+                UnitErrorKind::Negative,
+            ));
+        }
+        Self::try_new(
+            Self::new_from_raw(value, reference, multiplier).value,
+            reference,
+            multiplier,
+        )
+    }
+
+    pub fn try_new(
+        ratio: NativeType,
+        reference: UnitType,
+        multiplier: DecibelType,
+    ) -> Result<Self, UnitError> {
+        if !ratio.is_finite() {
+            return Err(UnitError::new_with_label(
+                "Decibel",
+                "decibels",
+                ratio,
+                UnitErrorKind::NonFinite,
+            ));
+        }
+        Ok(Self::new(ratio, reference, multiplier))
+    }
+
+    pub fn new(ratio: NativeType, reference: UnitType, multiplier: DecibelType) -> Self {
+        Self {
+            value: ratio,
+            reference,
+            multiplier,
+            convention: None,
+        }
+    }
+
+    /// The level in decibels
+    pub fn value(&self) -> NativeType {
+        self.value
+    }
+
+    pub fn reference(&self) -> UnitType {
+        self.reference
+    }
+
+    pub fn multiplier(&self) -> DecibelType {
+        self.multiplier
+    }
+
+    /// The named reference the level was created with (e.g. `Dbm`)
+    pub fn convention(&self) -> Option<DecibelReference> {
+        self.convention
+    }
+
+    /// The same level expressed relative to `reference`
+    pub fn with_reference(&self, reference: UnitType) -> Self {
+        let ratio: NativeType = self.reference.into() / reference.into();
+        let mult: NativeType = self.multiplier.into();
+
+        #[cfg(feature = "f32")]
+        let offset = mult * libm::log10f(ratio);
+        #[cfg(not(feature = "f32"))]
+        let offset = mult * libm::log10(ratio);
+
+        Self::new(self.value + offset, reference, self.multiplier)
+    }
+
+    pub fn ratio(&self) -> Scalar {
+        let mult: NativeType = self.multiplier.into();
+
+        #[cfg(feature = "f32")]
+        {
+            Scalar::from(libm::powf(10.0, self.value / mult))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Scalar::from(libm::pow(10.0, self.value / mult))
+        }
+    }
+
+    pub fn to_units(&self) -> UnitType {
+        self.reference * self.ratio()
+    }
+}
+
+/// √0.6 volts, the voltage of 1 mW into 600 ohms
+#[cfg(feature = "f32")]
+const DBU_VOLTS: NativeType = 0.774_596_7;
+
+#[cfg(not(feature = "f32"))]
+const DBU_VOLTS: NativeType = 0.774_596_669_241_483_4;
+
+/// Well-known decibel reference levels
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecibelReference {
+    /// Power relative to 1 milliwatt
+    Dbm,
+    /// Power relative to 1 watt
+    Dbw,
+    /// Voltage relative to 1 volt
+    Dbv,
+    /// Voltage relative to 0.7746 volts (1 mW into 600 ohms)
+    Dbu,
+    /// Sound pressure relative to 20 micropascals (`constants::p0`)
+    DbSpl,
+    /// Level relative to the full scale of a converter
+    Dbfs,
+}
+
+impl DecibelReference {
+    const ALL: [DecibelReference; 6] = [
+        DecibelReference::Dbm,
+        DecibelReference::Dbw,
+        DecibelReference::Dbv,
+        DecibelReference::Dbu,
+        DecibelReference::DbSpl,
+        DecibelReference::Dbfs,
+    ];
+
+    /// The text after `dB` (e.g. `m` for dBm)
+    pub const fn suffix(&self) -> &'static str {
+        match self {
+            DecibelReference::Dbm => "m",
+            DecibelReference::Dbw => "W",
+            DecibelReference::Dbv => "V",
+            DecibelReference::Dbu => "u",
+            DecibelReference::DbSpl => " SPL",
+            DecibelReference::Dbfs => "FS",
+        }
+    }
+
+    /// The reference value in the quantity's base units
+    pub const fn value(&self) -> NativeType {
+        match self {
+            DecibelReference::Dbm => 1.0E-3,
+            DecibelReference::Dbw => 1.0,
+            DecibelReference::Dbv => 1.0,
+            DecibelReference::Dbu => DBU_VOLTS,
+            DecibelReference::DbSpl => 2.0E-5,
+            DecibelReference::Dbfs => 1.0,
+        }
+    }
+
+    pub const fn multiplier(&self) -> DecibelType {
+        match self {
+            DecibelReference::Dbm | DecibelReference::Dbw => DecibelType::Power,
+            _ => DecibelType::Signal,
+        }
+    }
+
+    const fn quantity(&self) -> &'static str {
+        match self {
+            DecibelReference::Dbm | DecibelReference::Dbw => "Power",
+            DecibelReference::Dbv | DecibelReference::Dbu => "ElectricPotential",
+            DecibelReference::DbSpl => "Pressure",
+            DecibelReference::Dbfs => "Scalar",
+        }
+    }
+}
+
+impl<UnitType: Quantity + core::ops::Mul<Scalar, Output = UnitType>> Decibel<UnitType> {
+    fn named(value: NativeType, convention: DecibelReference) -> Self {
+        Self {
+            value,
+            reference: UnitType::from(convention.value()),
+            multiplier: convention.multiplier(),
+            convention: Some(convention),
+        }
+    }
+
+    fn to_named(self, convention: DecibelReference) -> Self {
+        Self {
+            convention: Some(convention),
+            ..self.with_reference(UnitType::from(convention.value()))
+        }
+    }
+
+    /// The suffix after `dB` for the reference, if it is a well-known one (e.g. `m` for 1 mW)
+    ///
+    /// A `Scalar` reference of 1 (a plain gain) has an empty suffix. A level
+    /// with the wrong `DecibelType` for a named reference (e.g. a signal
+    /// decibel relative to 1 W) has no suffix.
+    pub fn suffix(&self) -> Option<&'static str> {
+        if let Some(convention) = self.convention {
+            return (convention.multiplier() == self.multiplier).then_some(convention.suffix());
+        }
+        let reference: NativeType = self.reference.into();
+        if UnitType::NAME == "Scalar" {
+            return (reference == 1.0).then_some("");
+        }
+        DecibelReference::ALL
+            .iter()
+            .find(|convention| {
+                convention.quantity() == UnitType::NAME
+                    && convention.multiplier() == self.multiplier
+                    && (reference - convention.value()).abs() <= convention.value() * 1.0E-6
+            })
+            .map(|convention| convention.suffix())
+    }
+}
+
+impl Decibel<Power> {
+    pub fn dbm(value: NativeType) -> Self {
+        Self::named(value, DecibelReference::Dbm)
+    }
+
+    pub fn dbw(value: NativeType) -> Self {
+        Self::named(value, DecibelReference::Dbw)
+    }
+
+    pub fn to_dbm(&self) -> Self {
+        self.to_named(DecibelReference::Dbm)
+    }
+
+    pub fn to_dbw(&self) -> Self {
+        self.to_named(DecibelReference::Dbw)
+    }
+}
+
+impl Decibel<ElectricPotential> {
+    pub fn dbv(value: NativeType) -> Self {
+        Self::named(value, DecibelReference::Dbv)
+    }
+
+    pub fn dbu(value: NativeType) -> Self {
+        Self::named(value, DecibelReference::Dbu)
+    }
+
+    pub fn to_dbv(&self) -> Self {
+        self.to_named(DecibelReference::Dbv)
+    }
+
+    pub fn to_dbu(&self) -> Self {
+        self.to_named(DecibelReference::Dbu)
+    }
+}
+
+impl Decibel<Pressure> {
+    pub fn db_spl(value: NativeType) -> Self {
+        Self::named(value, DecibelReference::DbSpl)
+    }
+
+    pub fn to_db_spl(&self) -> Self {
+        self.to_named(DecibelReference::DbSpl)
+    }
+}
+
+impl Decibel<Scalar> {
+    /// Level relative to the full scale of a converter, where full scale is 1
+    pub fn dbfs(value: NativeType) -> Self {
+        Self::named(value, DecibelReference::Dbfs)
+    }
+
+    /// Whether this is a gain (relative to 1 with no named reference) that
+    /// can be added to a level, rather than a level such as dBFS
+    pub fn is_gain(&self) -> bool {
+        self.convention.is_none() && self.reference.native == 1.0
+    }
+}
+
+#[cfg(feature = "use_defmt")]
+impl<UnitType: Quantity + defmt::Format + core::ops::Mul<Scalar, Output = UnitType>> defmt::Format
+    for Decibel<UnitType>
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self.suffix() {
+            Some(suffix) => defmt::write!(f, "{} dB{}", self.value, suffix),
+            None => defmt::write!(f, "{} dB re {}", self.value, self.reference),
+        }
+    }
+}
+
+impl<UnitType: Quantity + core::fmt::Display + core::ops::Mul<Scalar, Output = UnitType>>
+    core::fmt::Display for Decibel<UnitType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} dB", precision, self.value)?,
+            None => write!(f, "{} dB", self.value)?,
+        }
+        match self.suffix() {
+            Some(suffix) => write!(f, "{}", suffix),
+            None => write!(f, " re {}", self.reference),
+        }
+    }
+}
+
+impl<UnitType: Quantity + core::fmt::Debug + core::ops::Mul<Scalar, Output = UnitType>>
+    core::fmt::Debug for Decibel<UnitType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Decibel")
+            .field("value", &self.value)
+            .field("reference", &self.reference)
+            .field("multiplier", &self.multiplier)
+            .field("convention", &self.convention)
+            .finish()
+    }
+}
+
+#[cfg(feature = "f32")]
+fn to_linear(value: NativeType, multiplier: NativeType) -> NativeType {
+    libm::powf(10.0, value / multiplier)
+}
+
+#[cfg(not(feature = "f32"))]
+fn to_linear(value: NativeType, multiplier: NativeType) -> NativeType {
+    libm::pow(10.0, value / multiplier)
+}
+
+#[cfg(feature = "f32")]
+fn from_linear(ratio: NativeType, multiplier: NativeType) -> NativeType {
+    multiplier * libm::log10f(ratio)
+}
+
+#[cfg(not(feature = "f32"))]
+fn from_linear(ratio: NativeType, multiplier: NativeType) -> NativeType {
+    multiplier * libm::log10(ratio)
+}
+
+impl<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>>
+    Decibel<UnitType>
+{
+    /// Expresses `other` relative to this level's reference, failing if it is
+    /// a different `DecibelType`
+    fn matching(&self, other: &Self) -> Result<NativeType, UnitError> {
+        if self.multiplier != other.multiplier {
+            return Err(UnitError::new_with_label(
+                "Decibel",
+                "decibels",
+                other.value,
+                UnitErrorKind::MismatchedDecibelType,
+            ));
+        }
+        let ratio: NativeType = other.reference.into() / self.reference.into();
+        let offset = from_linear(ratio, self.multiplier.into());
+        Ok(other.value + offset)
+    }
+
+    fn with_value(&self, value: NativeType) -> Result<Self, UnitError> {
+        if !value.is_finite() {
+            return Err(UnitError::new_with_label(
+                "Decibel",
+                "decibels",
+                value,
+                UnitErrorKind::NonFinite,
+            ));
+        }
+        Ok(Self { value, ..*self })
+    }
+
+    /// Total of two uncorrelated sources: `10 log10(10^(a/10) + 10^(b/10))`
+    ///
+    /// `other` is converted to this level's reference first.
+    pub fn power_sum(&self, other: &Self) -> Result<Self, UnitError> {
+        let other = self.matching(other)?;
+        self.with_value(from_linear(
+            to_linear(self.value, 10.0) + to_linear(other, 10.0),
+            10.0,
+        ))
+    }
+
+    /// Total of two in-phase signals: `20 log10(10^(a/20) + 10^(b/20))`
+    ///
+    /// `other` is converted to this level's reference first.
+    pub fn coherent_sum(&self, other: &Self) -> Result<Self, UnitError> {
+        let other = self.matching(other)?;
+        self.with_value(from_linear(
+            to_linear(self.value, 20.0) + to_linear(other, 20.0),
+            20.0,
+        ))
+    }
+
+    /// Applies `gain` to the level, failing with `NotAGain` if it isn't a gain
+    ///
+    /// Prefer this to `+` when `gain` comes from elsewhere, since `+` panics
+    /// on a level such as dBFS.
+    pub fn checked_add(&self, gain: Decibel<Scalar>) -> Result<Self, UnitError> {
+        if !gain.is_gain() {
+            return Err(UnitError::new_with_label(
+                "Decibel",
+                "decibels",
+                gain.value,
+                UnitErrorKind::NotAGain,
+            ));
+        }
+        self.with_value(self.value + gain.value)
+    }
+
+    /// Removes `gain` from the level, failing with `NotAGain` if it isn't a gain
+    ///
+    /// Prefer this to `-` when `gain` comes from elsewhere, since `-` panics
+    /// on a level such as dBFS.
+    pub fn checked_sub(&self, gain: Decibel<Scalar>) -> Result<Self, UnitError> {
+        self.checked_add(Decibel {
+            value: -gain.value,
+            ..gain
+        })
+    }
+
+    /// The gain from `other` to this level
+    pub fn gain_from(&self, other: &Self) -> Result<Decibel<Scalar>, UnitError> {
+        let other = self.matching(other)?;
+        Ok(Decibel::new(
+            self.value - other,
+            Scalar::new(1.0),
+            self.multiplier,
+        ))
+    }
+
+    /// Averages levels as power (in the linear domain) rather than averaging
+    /// the decibel numbers
+    ///
+    /// The result uses the reference of the first level. Fails with `Empty`
+    /// if there are no levels.
+    pub fn power_average(levels: impl IntoIterator<Item = Self>) -> Result<Self, UnitError> {
+        let mut levels = levels.into_iter();
+        let Some(first) = levels.next() else {
+            return Err(UnitError::new_with_label(
+                "Decibel",
+                "decibels",
+                0.0,
+                UnitErrorKind::Empty,
+            ));
+        };
+        let mut sum = to_linear(first.value, 10.0);
+        let mut count: NativeType = 1.0;
+        for level in levels {
+            sum += to_linear(first.matching(&level)?, 10.0);
+            count += 1.0;
+        }
+        first.with_value(from_linear(sum / count, 10.0))
+    }
+}
+
+/// Applies a gain to a level (or combines two gains)
+///
+/// Use `power_sum` or `coherent_sum` to combine two levels.
+///
+/// # Panics
+///
+/// Panics if `rhs` isn't a gain (see `Decibel::is_gain`), e.g. for
+/// `dbm(10.0) + dbfs(-6.0)`. Use `Decibel::checked_add` to get an error
+/// instead.
+impl<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>>
+    core::ops::Add<Decibel<Scalar>> for Decibel<UnitType>
+{
+    type Output = Decibel<UnitType>;
+
+    fn add(self, rhs: Decibel<Scalar>) -> Self::Output {
+        assert!(
+            rhs.is_gain(),
+            "only a gain (dB re 1) can be added to a level"
+        );
+        Self {
+            value: self.value + rhs.value,
+            ..self
+        }
+    }
+}
+
+/// Removes a gain from a level
+///
+/// Use `gain_from` for the difference between two levels.
+///
+/// # Panics
+///
+/// Panics if `rhs` isn't a gain (see `Decibel::is_gain`). Use
+/// `Decibel::checked_sub` to get an error instead.
+impl<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>>
+    core::ops::Sub<Decibel<Scalar>> for Decibel<UnitType>
+{
+    type Output = Decibel<UnitType>;
+
+    fn sub(self, rhs: Decibel<Scalar>) -> Self::Output {
+        assert!(
+            rhs.is_gain(),
+            "only a gain (dB re 1) can be subtracted from a level"
+        );
+        Self {
+            value: self.value - rhs.value,
+            ..self
+        }
+    }
+}
+
+impl<
+    UnitType: Into<NativeType> + From<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>,
+> core::ops::Mul<UnitType> for Decibel<UnitType>
+{
+    type Output = UnitType;
+
+    fn mul(self, rhs: UnitType) -> UnitType {
+        UnitType::from(self.ratio().native * rhs.into())
+    }
+}
+
+const ZERO_OFFSET: NativeType = 0.0;
+#[cfg(feature = "f32")]
+const DECIBELS_PER_NEPER: NativeType = 20.0 / core::f32::consts::LN_10;
+#[cfg(not(feature = "f32"))]
+const DECIBELS_PER_NEPER: NativeType = 20.0 / core::f64::consts::LN_10;
+
+#[cfg(feature = "f32")]
+const OCTAVES_PER_DECADE: NativeType = core::f32::consts::LOG2_10;
+#[cfg(not(feature = "f32"))]
+const OCTAVES_PER_DECADE: NativeType = core::f64::consts::LOG2_10;
+
+/// Natural logarithm of an amplitude ratio (1 Np is about 8.686 dB)
+#[derive(Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, Decode, Encode)]
+pub struct Neper {
+    native: NativeType,
+}
+
+impl Neper {
+    /// Nepers for an amplitude (signal) ratio
+    pub fn from_ratio(ratio: Scalar) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::logf(ratio.native))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::log(ratio.native))
+        }
+    }
+
+    /// The amplitude ratio
+    pub fn ratio(&self) -> Scalar {
+        #[cfg(feature = "f32")]
+        {
+            Scalar::from(libm::expf(self.native))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Scalar::from(libm::exp(self.native))
+        }
+    }
+}
+
+impl From<Neper> for Decibel<Scalar> {
+    fn from(value: Neper) -> Self {
+        Decibel::new(
+            value.native * DECIBELS_PER_NEPER,
+            Scalar::new(1.0),
+            DecibelType::Signal,
+        )
+    }
+}
+
+impl From<Decibel<Scalar>> for Neper {
+    fn from(value: Decibel<Scalar>) -> Self {
+        Neper::from(value.value / DECIBELS_PER_NEPER)
+    }
+}
+
+/// Base-10 logarithm of a power ratio (1 B is 10 dB)
+#[derive(Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, Decode, Encode)]
+pub struct Bel {
+    native: NativeType,
+}
+
+impl Bel {
+    /// Bels for a power ratio
+    pub fn from_ratio(ratio: Scalar) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::log10f(ratio.native))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::log10(ratio.native))
+        }
+    }
+
+    /// The power ratio
+    pub fn ratio(&self) -> Scalar {
+        #[cfg(feature = "f32")]
+        {
+            Scalar::from(libm::powf(10.0, self.native))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Scalar::from(libm::pow(10.0, self.native))
+        }
+    }
+}
+
+impl From<Bel> for Decibel<Scalar> {
+    fn from(value: Bel) -> Self {
+        Decibel::new(value.native * 10.0, Scalar::new(1.0), DecibelType::Power)
+    }
+}
+
+impl From<Decibel<Scalar>> for Bel {
+    fn from(value: Decibel<Scalar>) -> Self {
+        Bel::from(value.value / 10.0)
+    }
+}
+
+/// Base-2 logarithm of a frequency ratio
+#[derive(
+    Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, SiConvert, Decode, Encode,
+)]
+#[parameters(multiplier = OCTAVES_PER_DECADE, offset = ZERO_OFFSET, into = Decade)]
+pub struct Octave {
+    native: NativeType,
+}
+
+impl Octave {
+    /// Octaves from `low` up to `high`
+    pub fn between(low: Frequency, high: Frequency) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::log2f(high.native / low.native))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::log2(high.native / low.native))
+        }
+    }
+
+    /// The frequency ratio
+    pub fn ratio(&self) -> Scalar {
+        #[cfg(feature = "f32")]
+        {
+            Scalar::from(libm::exp2f(self.native))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Scalar::from(libm::exp2(self.native))
+        }
+    }
+
+    /// The frequency this many octaves above `frequency`
+    pub fn above(&self, frequency: Frequency) -> Frequency {
+        frequency * self.ratio()
+    }
+}
+
+/// Base-10 logarithm of a frequency ratio
+#[derive(Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, Decode, Encode)]
+pub struct Decade {
+    native: NativeType,
+}
+
+impl Decade {
+    /// Decades from `low` up to `high`
+    pub fn between(low: Frequency, high: Frequency) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::log10f(high.native / low.native))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::log10(high.native / low.native))
+        }
+    }
+
+    /// The frequency ratio
+    pub fn ratio(&self) -> Scalar {
+        #[cfg(feature = "f32")]
+        {
+            Scalar::from(libm::powf(10.0, self.native))
+        }
+        #[cfg(not(feature = "f32"))]
+        {
+            Scalar::from(libm::pow(10.0, self.native))
+        }
+    }
+
+    /// The frequency this many decades above `frequency`
+    pub fn above(&self, frequency: Frequency) -> Frequency {
+        frequency * self.ratio()
+    }
+}
+
+/// Slope of a frequency response (e.g. 6 dB/octave for a first-order filter)
+#[derive(Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, Decode, Encode)]
+pub struct DecibelsPerOctave {
+    native: NativeType,
+}
+
+/// Slope of a frequency response (e.g. 20 dB/decade for a first-order filter)
+#[derive(
+    Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, SiConvert, Decode, Encode,
+)]
+#[parameters(multiplier = OCTAVES_PER_DECADE, offset = ZERO_OFFSET, into = DecibelsPerOctave)]
+pub struct DecibelsPerDecade {
+    native: NativeType,
+}
+
+impl core::ops::Mul<Octave> for DecibelsPerOctave {
+    type Output = Decibel<Scalar>;
+
+    fn mul(self, rhs: Octave) -> Decibel<Scalar> {
+        Decibel::new(
+            self.native * rhs.native,
+            Scalar::new(1.0),
+            DecibelType::Signal,
+        )
+    }
+}
+
+impl core::ops::Mul<Decade> for DecibelsPerDecade {
+    type Output = Decibel<Scalar>;
+
+    fn mul(self, rhs: Decade) -> Decibel<Scalar> {
+        Decibel::new(
+            self.native * rhs.native,
+            Scalar::new(1.0),
+            DecibelType::Signal,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_references() {
+        let level = Decibel::dbm(30.0);
+        assert_eq!(level.to_string(), "30 dBm");
+        assert_eq!(level.to_units(), Power::new(1.0));
+        let level = level.to_dbw();
+        assert!(level.value().abs() < 1e-9);
+        assert_eq!(level.convention(), Some(DecibelReference::Dbw));
+        assert_eq!(format!("{:.1}", level.to_dbm()), "30.0 dBm");
+
+        let level = Decibel::dbv(0.0).to_dbu();
+        assert_eq!(format!("{:.2}", level), "2.22 dBu");
+        assert_eq!(level.to_units(), ElectricPotential::new(1.0));
+
+        let level = Decibel::db_spl(94.0);
+        assert!((level.to_units() - Pressure::new(1.0)).abs() < Pressure::new(0.01));
+        assert_eq!(Decibel::dbfs(-6.0).to_string(), "-6 dBFS");
+
+        // an explicit reference of 1 mW is recognised without the constructor
+        let level = Decibel::new(10.0, Power::new(0.001), DecibelType::Power);
+        assert_eq!(level.suffix(), Some("m"));
+        let level = level.with_reference(Power::new(0.01));
+        assert!(level.value().abs() < 1e-4);
+        assert_eq!(level.suffix(), None);
+
+        // dB re 1 W is only dBW for power decibels
+        let level = Decibel::new(10.0, Power::new(1.0), DecibelType::Signal);
+        assert_eq!(level.suffix(), None);
+        assert_eq!(level.to_string(), "10 dB re 1 watt");
+        assert_eq!(level.to_dbw().suffix(), None);
+
+        let json = serde_json::to_string(&Decibel::dbm(12.0)).unwrap();
+        let restored: Decibel<Power> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.to_string(), "12 dBm");
+    }
+
+    #[test]
+    fn decibel_arithmetic() {
+        let gain = Decibel::new(20.0, Scalar::new(1.0), DecibelType::Signal);
+        let level = Decibel::dbv(-26.0) + gain;
+        assert_eq!(level.to_string(), "-6 dBV");
+        assert_eq!((level - gain).to_string(), "-26 dBV");
+        assert!(gain.is_gain());
+        assert!(!Decibel::dbfs(-6.0).is_gain());
+        assert_eq!(
+            Decibel::dbv(-26.0).checked_add(gain).unwrap().to_string(),
+            "-6 dBV"
+        );
+        assert_eq!(level.checked_sub(gain).unwrap().to_string(), "-26 dBV");
+        let error = Decibel::dbm(10.0)
+            .checked_add(Decibel::dbfs(-6.0))
+            .unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::NotAGain);
+        let reference = Decibel::new(3.0, Scalar::new(2.0), DecibelType::Power);
+        assert!(Decibel::dbm(10.0).checked_sub(reference).is_err());
+
+        let total = Decibel::dbm(10.0).power_sum(&Decibel::dbm(10.0)).unwrap();
+        assert_eq!(format!("{:.2}", total), "13.01 dBm");
+        let total = Decibel::dbm(10.0).power_sum(&Decibel::dbw(-20.0)).unwrap();
+        assert_eq!(format!("{:.2}", total), "13.01 dBm");
+        let total = Decibel::dbv(0.0).coherent_sum(&Decibel::dbv(0.0)).unwrap();
+        assert_eq!(format!("{:.2}", total), "6.02 dBV");
+
+        let average = Decibel::power_average([Decibel::dbm(10.0), Decibel::dbm(20.0)]).unwrap();
+        assert_eq!(format!("{:.2}", average), "17.40 dBm");
+        let error = Decibel::<Power>::power_average([]).unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::Empty);
+
+        let gain = Decibel::dbm(20.0).gain_from(&Decibel::dbw(-20.0)).unwrap();
+        assert_eq!(format!("{:.1}", gain), "10.0 dB");
+
+        let mixed = Decibel::new(10.0, Power::new(0.001), DecibelType::Signal);
+        let error = Decibel::dbm(10.0).power_sum(&mixed).unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::MismatchedDecibelType);
+    }
+
+    #[test]
+    fn logarithmic_units() {
+        let decibels: Decibel<Scalar> = Neper::new(1.0).into();
+        assert_eq!(format!("{:.3}", decibels), "8.686 dB");
+        assert_eq!(Neper::from(decibels), Neper::new(1.0));
+        assert_eq!(
+            Neper::from_ratio(Scalar::new(2.0)).ratio(),
+            Scalar::new(2.0)
+        );
+
+        let decibels: Decibel<Scalar> = Bel::new(3.0).into();
+        assert_eq!(decibels.to_string(), "30 dB");
+        assert_eq!(decibels.ratio(), Bel::new(3.0).ratio());
+        assert_eq!(Bel::from_ratio(Scalar::new(1000.0)), Bel::new(3.0));
+
+        let octaves = Octave::between(Frequency::new(440.0), Frequency::new(1760.0));
+        assert_eq!(octaves, Octave::new(2.0));
+        assert_eq!(octaves.above(Frequency::new(50.0)), Frequency::new(200.0));
+        let decade: Decade = Octave::new(OCTAVES_PER_DECADE).into();
+        assert_eq!(decade, Decade::new(1.0));
+        assert_eq!(
+            Decade::between(Frequency::new(20.0), Frequency::new(2000.0)),
+            Decade::new(2.0)
+        );
+
+        let slope: DecibelsPerOctave = DecibelsPerDecade::new(20.0).into();
+        assert_eq!(format!("{:.2}", slope * Octave::new(1.0)), "6.02 dB");
+        assert_eq!(
+            (DecibelsPerDecade::new(-40.0) * Decade::new(2.0)).to_string(),
+            "-80 dB"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "only a gain")]
+    fn level_added_as_gain() {
+        let _ = Decibel::dbm(10.0) + Decibel::dbfs(-6.0);
+    }
+}
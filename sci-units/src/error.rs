@@ -0,0 +1,158 @@
+use crate::{NativeType, Quantity};
+
+/// The constraint that a value failed to satisfy
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UnitErrorKind {
+    /// The value is NaN or infinite (e.g. the result of dividing by zero)
+    NonFinite,
+    /// The value is negative for a quantity that can't be negative (e.g. `Mass`)
+    Negative,
+    /// The value is colder than absolute zero
+    BelowAbsoluteZero,
+    /// The value is outside of a range, which `UnitError::limits` returns
+    OutOfRange,
+    /// The function has the same sign at both ends of a root-finding bracket
+    NotBracketed,
+    /// An iterative method couldn't meet its tolerance
+    NotConverged,
+    /// A step or size that must be greater than zero isn't
+    NotPositive,
+    /// Samples that must be evenly spaced in time aren't
+    UnevenSpacing,
+    /// There are too few distinct points to determine a unique solution
+    Singular,
+    /// A table point isn't after the one before it (e.g. a `LookupTable` `X`)
+    NotIncreasing,
+    /// Decibels of power and signal (field) quantities were combined
+    MismatchedDecibelType,
+    /// A level was applied as a gain (e.g. dBFS added to dBm)
+    NotAGain,
+    /// A calculation that needs at least one value was given none (the
+    /// error's value is the count, zero)
+    Empty,
+}
+
+impl UnitErrorKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            UnitErrorKind::NonFinite => "is not finite",
+            UnitErrorKind::Negative => "is negative",
+            UnitErrorKind::BelowAbsoluteZero => "is below absolute zero",
+            UnitErrorKind::OutOfRange => "is out of range",
+            UnitErrorKind::NotBracketed => "does not change sign over the bracket",
+            UnitErrorKind::NotConverged => "did not converge",
+            UnitErrorKind::NotPositive => "is not positive",
+            UnitErrorKind::UnevenSpacing => "is not the same as the other intervals",
+            UnitErrorKind::Singular => "don't determine a unique solution",
+            UnitErrorKind::NotIncreasing => "is not greater than the previous point",
+            UnitErrorKind::MismatchedDecibelType => "mixes power and signal decibels",
+            UnitErrorKind::NotAGain => "is a level, not a gain relative to 1",
+            UnitErrorKind::Empty => "were given, but at least one is needed",
+        }
+    }
+}
+
+/// Error returned by the `try_new` and `checked_*` family of functions
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnitError {
+    quantity: &'static str,
+    label: &'static str,
+    value: NativeType,
+    kind: UnitErrorKind,
+    limits: Option<(NativeType, NativeType)>,
+}
+
+impl UnitError {
+    pub fn new<T: Quantity>(value: NativeType, kind: UnitErrorKind) -> Self {
+        Self::new_with_label(T::NAME, T::LABEL, value, kind)
+    }
+
+    pub const fn new_with_label(
+        quantity: &'static str,
+        label: &'static str,
+        value: NativeType,
+        kind: UnitErrorKind,
+    ) -> Self {
+        Self {
+            quantity,
+            label,
+            value,
+            kind,
+            limits: None,
+        }
+    }
+
+    /// An `OutOfRange` error for a value outside of `minimum..=maximum`
+    pub fn out_of_range<T: Quantity>(
+        value: NativeType,
+        minimum: NativeType,
+        maximum: NativeType,
+    ) -> Self {
+        Self {
+            limits: Some((minimum, maximum)),
+            ..Self::new::<T>(value, UnitErrorKind::OutOfRange)
+        }
+    }
+
+    /// Name of the quantity type that was rejected (e.g. `"Mass"`)
+    pub fn quantity(&self) -> &'static str {
+        self.quantity
+    }
+
+    /// Unit label of the rejected value (e.g. `"kilograms"`)
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// The offending value in the units of `label()`
+    pub fn value(&self) -> NativeType {
+        self.value
+    }
+
+    pub fn kind(&self) -> UnitErrorKind {
+        self.kind
+    }
+
+    /// The `(minimum, maximum)` of the range for an `OutOfRange` error
+    pub fn limits(&self) -> Option<(NativeType, NativeType)> {
+        self.limits
+    }
+}
+
+impl core::fmt::Display for UnitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}: {} {} {}",
+            self.quantity,
+            self.value,
+            self.label,
+            self.kind.description()
+        )?;
+        if let Some((minimum, maximum)) = self.limits {
+            write!(f, " [{}, {}] {}", minimum, maximum, self.label)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitError {}
+
+#[cfg(feature = "use_defmt")]
+impl defmt::Format for UnitError {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "{}: {} {} {}",
+            self.quantity,
+            self.value,
+            self.label,
+            self.kind.description()
+        );
+        if let Some((minimum, maximum)) = self.limits {
+            defmt::write!(f, " [{}, {}] {}", minimum, maximum, self.label);
+        }
+    }
+}
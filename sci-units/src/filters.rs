@@ -0,0 +1,383 @@
+use crate::{Frequency, NativeType, PI, Quantity, Scalar, Time};
+
+/// A filter that takes and returns samples of the same quantity
+///
+/// Every filter starts as if it had always seen its first sample, so the
+/// first output is the steady-state response to that sample (the sample
+/// itself for low-pass filters and averages) rather than a step from zero.
+/// `reset` returns a filter to that start-up state.
+pub trait Filter<T> {
+    /// Adds a sample and returns the filtered value
+    fn update(&mut self, input: T) -> T;
+
+    /// Forgets all previous samples
+    fn reset(&mut self);
+}
+
+/// First-order IIR low-pass filter
+///
+/// The output starts at the first input value.
+#[derive(Copy, Clone)]
+pub struct LowPass<T> {
+    alpha: NativeType,
+    state: Option<T>,
+}
+
+impl<T: Quantity> LowPass<T> {
+    pub fn new(cutoff: Frequency, period: Time) -> Self {
+        let time_constant = 1.0 / (2.0 * PI * cutoff.native);
+        Self {
+            alpha: period.native / (time_constant + period.native),
+            state: None,
+        }
+    }
+
+    pub fn value(&self) -> Option<T> {
+        self.state
+    }
+}
+
+impl<T: Quantity> Filter<T> for LowPass<T> {
+    fn update(&mut self, input: T) -> T {
+        let output = match self.state {
+            Some(state) => {
+                let state: NativeType = state.into();
+                T::from(state + self.alpha * (input.into() - state))
+            }
+            None => input,
+        };
+        self.state = Some(output);
+        output
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Exponential smoothing with a fixed smoothing factor between 0 and 1
+///
+/// The output starts at the first input value.
+#[derive(Copy, Clone)]
+pub struct ExponentialSmoothing<T> {
+    alpha: NativeType,
+    state: Option<T>,
+}
+
+impl<T: Quantity> ExponentialSmoothing<T> {
+    pub fn new(alpha: Scalar) -> Self {
+        Self {
+            alpha: alpha.native,
+            state: None,
+        }
+    }
+
+    pub fn value(&self) -> Option<T> {
+        self.state
+    }
+}
+
+impl<T: Quantity> Filter<T> for ExponentialSmoothing<T> {
+    fn update(&mut self, input: T) -> T {
+        let output = match self.state {
+            Some(state) => T::from(
+                self.alpha * input.into() + (1.0 - self.alpha) * Into::<NativeType>::into(state),
+            ),
+            None => input,
+        };
+        self.state = Some(output);
+        output
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Second-order IIR filter (transposed direct form II)
+///
+/// The coefficients are normalised so that `a0` is 1. The state starts at
+/// the steady state for the first input, unless the filter has no finite
+/// gain at DC, in which case it starts at zero.
+#[derive(Copy, Clone)]
+pub struct Biquad<T> {
+    b: [NativeType; 3],
+    a: [NativeType; 2],
+    state: Option<[NativeType; 2]>,
+    unit: core::marker::PhantomData<T>,
+}
+
+impl<T: Quantity> Biquad<T> {
+    /// `b` are the feed-forward and `a` the feedback coefficients `[a1, a2]`
+    pub fn new(b: [NativeType; 3], a: [NativeType; 2]) -> Self {
+        Self {
+            b,
+            a,
+            state: None,
+            unit: core::marker::PhantomData,
+        }
+    }
+
+    /// The state that a constant `input` settles to
+    fn steady_state(&self, input: NativeType) -> [NativeType; 2] {
+        let denominator = 1.0 + self.a[0] + self.a[1];
+        if denominator == 0.0 {
+            return [0.0; 2];
+        }
+        let output = input * self.b.iter().sum::<NativeType>() / denominator;
+        let second = self.b[2] * input - self.a[1] * output;
+        [self.b[1] * input - self.a[0] * output + second, second]
+    }
+
+    fn design(cutoff: Frequency, period: Time, q: Scalar) -> (NativeType, NativeType) {
+        let omega = 2.0 * PI * cutoff.native * period.native;
+
+        #[cfg(feature = "f32")]
+        let (sin, cos) = libm::sincosf(omega);
+        #[cfg(not(feature = "f32"))]
+        let (sin, cos) = libm::sincos(omega);
+
+        (sin / (2.0 * q.native), cos)
+    }
+
+    /// Second-order low-pass (use a `q` of 1/√2 for a Butterworth response)
+    pub fn low_pass(cutoff: Frequency, period: Time, q: Scalar) -> Self {
+        let (alpha, cos) = Self::design(cutoff, period, q);
+        let a0 = 1.0 + alpha;
+        let b1 = (1.0 - cos) / a0;
+        Self::new(
+            [b1 * 0.5, b1, b1 * 0.5],
+            [-2.0 * cos / a0, (1.0 - alpha) / a0],
+        )
+    }
+
+    /// Second-order high-pass (use a `q` of 1/√2 for a Butterworth response)
+    pub fn high_pass(cutoff: Frequency, period: Time, q: Scalar) -> Self {
+        let (alpha, cos) = Self::design(cutoff, period, q);
+        let a0 = 1.0 + alpha;
+        let b1 = -(1.0 + cos) / a0;
+        Self::new(
+            [-b1 * 0.5, b1, -b1 * 0.5],
+            [-2.0 * cos / a0, (1.0 - alpha) / a0],
+        )
+    }
+
+    /// Band-pass with a gain of one at the centre frequency
+    pub fn band_pass(center: Frequency, period: Time, q: Scalar) -> Self {
+        let (alpha, cos) = Self::design(center, period, q);
+        let a0 = 1.0 + alpha;
+        Self::new(
+            [alpha / a0, 0.0, -alpha / a0],
+            [-2.0 * cos / a0, (1.0 - alpha) / a0],
+        )
+    }
+}
+
+impl<T: Quantity> Filter<T> for Biquad<T> {
+    fn update(&mut self, input: T) -> T {
+        let input: NativeType = input.into();
+        let state = match self.state {
+            Some(state) => state,
+            None => self.steady_state(input),
+        };
+        let output = self.b[0] * input + state[0];
+        self.state = Some([
+            self.b[1] * input - self.a[0] * output + state[1],
+            self.b[2] * input - self.a[1] * output,
+        ]);
+        T::from(output)
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Average of the last `N` samples
+///
+/// Until `N` samples have been added, the average is of the samples so far.
+/// The sum is recalculated from the window on every update, so a NaN or
+/// infinite sample only affects the output while it is in the window.
+#[derive(Copy, Clone)]
+pub struct MovingAverage<T, const N: usize> {
+    samples: [NativeType; N],
+    next: usize,
+    count: usize,
+    unit: core::marker::PhantomData<T>,
+}
+
+impl<T: Quantity, const N: usize> MovingAverage<T, N> {
+    pub const fn new() -> Self {
+        assert!(N > 0, "a moving average needs at least one sample");
+        Self {
+            samples: [0.0; N],
+            next: 0,
+            count: 0,
+            unit: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: Quantity, const N: usize> Default for MovingAverage<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Quantity, const N: usize> Filter<T> for MovingAverage<T, N> {
+    fn update(&mut self, input: T) -> T {
+        self.samples[self.next] = input.into();
+        self.next = (self.next + 1) % N;
+        self.count = (self.count + 1).min(N);
+        let sum: NativeType = self.samples[..self.count].iter().sum();
+        T::from(sum / self.count as NativeType)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Median of the last `N` samples
+///
+/// Until `N` samples have been added, the median is of the samples so far.
+#[derive(Copy, Clone)]
+pub struct Median<T, const N: usize> {
+    samples: [NativeType; N],
+    next: usize,
+    count: usize,
+    unit: core::marker::PhantomData<T>,
+}
+
+impl<T: Quantity, const N: usize> Median<T, N> {
+    pub const fn new() -> Self {
+        assert!(N > 0, "a median filter needs at least one sample");
+        Self {
+            samples: [0.0; N],
+            next: 0,
+            count: 0,
+            unit: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: Quantity, const N: usize> Default for Median<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Quantity, const N: usize> Filter<T> for Median<T, N> {
+    fn update(&mut self, input: T) -> T {
+        self.samples[self.next] = input.into();
+        self.next = (self.next + 1) % N;
+        self.count = (self.count + 1).min(N);
+
+        let mut sorted = self.samples;
+        let sorted = &mut sorted[..self.count];
+        sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+        let middle = self.count / 2;
+        if self.count % 2 == 1 {
+            T::from(sorted[middle])
+        } else {
+            T::from((sorted[middle - 1] + sorted[middle]) * 0.5)
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Acceleration, ElectricCurrent, Pressure};
+
+    #[test]
+    fn low_pass_filters() {
+        let mut filter = LowPass::<Pressure>::new(Frequency::new(1.0), Time::new(0.01));
+        assert_eq!(filter.update(Pressure::new(100.0)), Pressure::new(100.0));
+        let mut output = Pressure::new(100.0);
+        for _ in 0..1000 {
+            output = filter.update(Pressure::new(200.0));
+        }
+        assert_eq!(output, Pressure::new(200.0));
+
+        let mut filter = ExponentialSmoothing::<Pressure>::new(Scalar::new(0.25));
+        filter.update(Pressure::new(100.0));
+        assert_eq!(filter.update(Pressure::new(200.0)), Pressure::new(125.0));
+        filter.reset();
+        assert!(filter.value().is_none());
+    }
+
+    #[test]
+    fn biquad_filters() {
+        let period = Time::new(0.01);
+        let q = Scalar::new(0.5).sqrt();
+        let mut low_pass = Biquad::<Acceleration>::low_pass(Frequency::new(10.0), period, q);
+        let mut high_pass = Biquad::<Acceleration>::high_pass(Frequency::new(10.0), period, q);
+        let (mut low, mut high) = (Acceleration::new(1.0), Acceleration::new(1.0));
+        for _ in 0..500 {
+            low = low_pass.update(Acceleration::new(9.8));
+            high = high_pass.update(Acceleration::new(9.8));
+        }
+        assert_eq!(low, Acceleration::new(9.8));
+        assert!(high.abs() < Acceleration::new(1e-6));
+
+        // like `LowPass`, the output starts at the first input rather than
+        // stepping up from zero
+        low_pass.reset();
+        high_pass.reset();
+        assert_eq!(
+            low_pass.update(Acceleration::new(9.8)),
+            Acceleration::new(9.8)
+        );
+        assert!(high_pass.update(Acceleration::new(9.8)).abs() < Acceleration::new(1e-6));
+        assert_eq!(
+            low_pass.update(Acceleration::new(9.8)),
+            Acceleration::new(9.8)
+        );
+    }
+
+    #[test]
+    fn window_filters() {
+        let mut average = MovingAverage::<ElectricCurrent, 3>::new();
+        assert_eq!(
+            average.update(ElectricCurrent::new(3.0)),
+            ElectricCurrent::new(3.0)
+        );
+        average.update(ElectricCurrent::new(6.0));
+        average.update(ElectricCurrent::new(9.0));
+        assert_eq!(
+            average.update(ElectricCurrent::new(12.0)),
+            ElectricCurrent::new(9.0)
+        );
+
+        // a bad sample only affects the average while it is in the window
+        let nan = average.update(ElectricCurrent::new(NativeType::NAN));
+        assert!(Into::<NativeType>::into(nan).is_nan());
+        average.update(ElectricCurrent::new(1.0));
+        average.update(ElectricCurrent::new(2.0));
+        assert_eq!(
+            average.update(ElectricCurrent::new(3.0)),
+            ElectricCurrent::new(2.0)
+        );
+
+        let mut median = Median::<ElectricCurrent, 3>::new();
+        median.update(ElectricCurrent::new(1.0));
+        assert_eq!(
+            median.update(ElectricCurrent::new(2.0)),
+            ElectricCurrent::new(1.5)
+        );
+        assert_eq!(
+            median.update(ElectricCurrent::new(100.0)),
+            ElectricCurrent::new(2.0)
+        );
+        assert_eq!(
+            median.update(ElectricCurrent::new(3.0)),
+            ElectricCurrent::new(3.0)
+        );
+    }
+}
@@ -0,0 +1,941 @@
+use core::fmt::{self, Alignment, Formatter, Write};
+use core::marker::PhantomData;
+
+use crate::render::{self, Markup, MarkupNumber};
+use crate::system::{DisplayUnit, UnitSystem};
+use crate::{NativeType, Prefix, Quantity, SIGNIFICANT_FIGURES};
+
+/// Counts what a number writes so it can be padded and grouped without a buffer
+#[derive(Default)]
+struct Measure {
+    chars: usize,
+    integer_digits: usize,
+    /// Past the decimal point or into the exponent
+    fraction: bool,
+    /// The first characters, to tell if the number is `"1"` or `"-1"`
+    start: [char; 2],
+}
+
+impl Measure {
+    fn is_one(&self) -> bool {
+        match self.chars {
+            1 => self.start[0] == '1',
+            2 => self.start == ['-', '1'],
+            _ => false,
+        }
+    }
+
+    /// Number of characters once the thousands separators are added
+    fn localized_chars(&self, locale: Locale) -> usize {
+        match locale.thousands {
+            Some(_) if self.integer_digits > 0 => self.chars + (self.integer_digits - 1) / 3,
+            _ => self.chars,
+        }
+    }
+}
+
+impl Write for Measure {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        for character in text.chars() {
+            if let Some(start) = self.start.get_mut(self.chars) {
+                *start = character;
+            }
+            self.chars += 1;
+            if character == '.' || character == 'e' {
+                self.fraction = true;
+            } else if character.is_ascii_digit() && !self.fraction {
+                self.integer_digits += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rewrites a number with the separators of a locale as it is written
+struct Localize<'a, W> {
+    output: &'a mut W,
+    locale: Locale,
+    integer_digits: usize,
+    written_digits: usize,
+    fraction: bool,
+}
+
+impl<'a, W> Localize<'a, W> {
+    fn new(output: &'a mut W, locale: Locale, measure: &Measure) -> Self {
+        Self {
+            output,
+            locale,
+            integer_digits: measure.integer_digits,
+            written_digits: 0,
+            fraction: false,
+        }
+    }
+}
+
+impl<W: Write> Write for Localize<'_, W> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        for character in text.chars() {
+            if character == '.' {
+                self.fraction = true;
+                self.output.write_char(self.locale.decimal)?;
+                continue;
+            }
+            if character == 'e' {
+                self.fraction = true;
+            }
+            if character.is_ascii_digit() && !self.fraction {
+                if let Some(thousands) = self.locale.thousands
+                    && self.written_digits > 0
+                    && (self.integer_digits - self.written_digits).is_multiple_of(3)
+                {
+                    self.output.write_char(thousands)?;
+                }
+                self.written_digits += 1;
+            }
+            self.output.write_char(character)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `length` characters with `write`, padded to the width, fill and alignment of `f`
+///
+/// Text is left-aligned unless another alignment is given.
+fn pad(
+    f: &mut Formatter<'_>,
+    length: usize,
+    write: impl FnOnce(&mut Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(length);
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// The power of ten of the leading digit of `value`
+#[cfg(feature = "f32")]
+fn decade(value: NativeType) -> i32 {
+    libm::floorf(libm::log10f(value.abs())) as i32
+}
+
+#[cfg(not(feature = "f32"))]
+fn decade(value: NativeType) -> i32 {
+    libm::floor(libm::log10(value.abs())) as i32
+}
+
+#[cfg(feature = "f32")]
+fn power_of_ten(exponent: i32) -> NativeType {
+    libm::powf(10.0, exponent as NativeType)
+}
+
+#[cfg(not(feature = "f32"))]
+fn power_of_ten(exponent: i32) -> NativeType {
+    libm::pow(10.0, exponent as NativeType)
+}
+
+#[cfg(feature = "f32")]
+fn round_integer(value: NativeType) -> NativeType {
+    libm::roundf(value)
+}
+
+#[cfg(not(feature = "f32"))]
+fn round_integer(value: NativeType) -> NativeType {
+    libm::round(value)
+}
+
+/// Rounds `value` to `decimals` places after the point (before it if negative)
+fn round(value: NativeType, decimals: i32) -> NativeType {
+    // dividing by an exact power of ten gives the float nearest the decimal
+    let scaled = if decimals >= 0 {
+        let scale = power_of_ten(decimals);
+        round_integer(value * scale) / scale
+    } else {
+        let scale = power_of_ten(-decimals);
+        round_integer(value / scale) * scale
+    };
+    if scaled.is_finite() { scaled } else { value }
+}
+
+/// Rounds `value` to `figures` significant figures
+fn round_significant(value: NativeType, figures: i32) -> NativeType {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    round(value, figures - 1 - decade(value))
+}
+
+/// Whether units are written as symbols (`"kΩ"`) or long names (`"kiloohms"`)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum UnitStyle {
+    Symbol,
+    #[default]
+    Name,
+}
+
+/// The decimal and thousands separators used to write numbers
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    pub decimal: char,
+    pub thousands: Option<char>,
+}
+
+impl Locale {
+    /// A point and no thousands separator, as written by `Display` for floats
+    pub const PLAIN: Locale = Locale::new('.', None);
+    /// `1,234.5`
+    pub const ENGLISH: Locale = Locale::new('.', Some(','));
+    /// `1.234,5`
+    pub const GERMAN: Locale = Locale::new(',', Some('.'));
+    /// `1 234,5` with a narrow no-break space
+    pub const FRENCH: Locale = Locale::new(',', Some('\u{202f}'));
+    /// `1'234.5`
+    pub const SWISS: Locale = Locale::new('.', Some('\''));
+
+    pub const fn new(decimal: char, thousands: Option<char>) -> Self {
+        Self { decimal, thousands }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::PLAIN
+    }
+}
+
+/// Supplies translated long names for units
+pub trait Translate {
+    /// The long name of the unit of `quantity` (a `Quantity::NAME`), or
+    /// `None` to use the English name
+    fn unit_name(&self, quantity: &'static str, plural: bool) -> Option<&str>;
+}
+
+/// Which SI prefix a value is written with
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Scaling {
+    /// No prefix (e.g. `"4700 Ω"`)
+    #[default]
+    None,
+    /// The prefix that puts the number between 1 and 1000 (e.g. `"4.7 kΩ"`)
+    Automatic,
+    /// Always the same prefix (e.g. `"1013.25 hPa"`)
+    Fixed(Prefix),
+}
+
+/// Formatting options for a quantity, chosen when it is formatted
+///
+/// By default this writes the value to `SIGNIFICANT_FIGURES` followed by the
+/// singular or plural long name of the unit (`"1 meter"`, `"2.5 meters"`),
+/// which is what `Display` does. Precision, width, fill and alignment are
+/// honoured. Unless a locale with separators is used the text parses back
+/// into the same quantity.
+#[derive(Copy, Clone)]
+pub struct Formatted<'a, T> {
+    value: NativeType,
+    style: UnitStyle,
+    scaling: Scaling,
+    ascii: bool,
+    locale: Locale,
+    translation: Option<&'a dyn Translate>,
+    figures: i32,
+    /// Values with a magnitude outside `[lower, upper)` use scientific notation
+    positional: (NativeType, NativeType),
+    markup: Markup,
+    unit: DisplayUnit,
+    quantity: PhantomData<T>,
+}
+
+impl<'a, T: Quantity> Formatted<'a, T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value: value.into(),
+            style: UnitStyle::Name,
+            scaling: Scaling::None,
+            ascii: false,
+            locale: Locale::PLAIN,
+            translation: None,
+            figures: SIGNIFICANT_FIGURES,
+            positional: (1e-3, 1e9),
+            markup: Markup::Text,
+            unit: DisplayUnit::of::<T>(),
+            quantity: PhantomData,
+        }
+    }
+
+    pub fn style(mut self, style: UnitStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Writes symbols (e.g. `"4.7 mF"`)
+    pub fn symbols(self) -> Self {
+        self.style(UnitStyle::Symbol)
+    }
+
+    /// Writes long names (e.g. `"4.7 millifarads"`)
+    pub fn names(self) -> Self {
+        self.style(UnitStyle::Name)
+    }
+
+    /// Picks an SI prefix so the number is between 1 and 1000
+    ///
+    /// Units that don't take prefixes (e.g. `°C`) are written without one.
+    pub fn engineering(mut self) -> Self {
+        self.scaling = Scaling::Automatic;
+        self
+    }
+
+    /// Always uses `prefix` (e.g. `Prefix::Milli` to show `"4700 mF"`)
+    pub fn prefix(mut self, prefix: Prefix) -> Self {
+        self.scaling = Scaling::Fixed(prefix);
+        self
+    }
+
+    /// Presents the value in the unit and prefix `system` prefers for `T`
+    ///
+    /// e.g. `"10000 feet"` for 3048 m with `UnitSystem::AVIATION`
+    pub fn system(mut self, system: &UnitSystem<'_>) -> Self {
+        let preference = system.preference::<T>();
+        self.unit = preference.unit;
+        self.scaling = preference.scaling;
+        self
+    }
+
+    /// Writes symbols using only ASCII (e.g. `"kOhm"`, `"degC"`, `"uF"`)
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Looks up long names with `translation` before using the English ones
+    pub fn translate(mut self, translation: &'a dyn Translate) -> Self {
+        self.translation = Some(translation);
+        self
+    }
+
+    /// Rounds to `figures` significant figures (`SIGNIFICANT_FIGURES` by default)
+    ///
+    /// A precision (e.g. `{:.2}`) overrides this with a number of decimal places.
+    pub fn significant_figures(mut self, figures: u32) -> Self {
+        self.figures = figures.clamp(1, 17) as i32;
+        self
+    }
+
+    /// Uses scientific notation (e.g. `"1.5e-7 meters"`) when the magnitude
+    /// is below `lower` or at least `upper` (by default `1e-3` and `1e9`)
+    pub fn scientific_outside(mut self, lower: NativeType, upper: NativeType) -> Self {
+        self.positional = (lower, upper);
+        self
+    }
+
+    /// Always uses scientific notation
+    pub fn scientific(self) -> Self {
+        self.scientific_outside(NativeType::INFINITY, NativeType::INFINITY)
+    }
+
+    /// Never uses scientific notation
+    pub fn positional(self) -> Self {
+        self.scientific_outside(0.0, NativeType::INFINITY)
+    }
+
+    /// Renders in a markup language, which always writes symbols and ignores width
+    pub fn markup(mut self, markup: Markup) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    /// siunitx LaTeX (e.g. `\qty{3.2}{\kilo\meter}`)
+    pub fn latex(self) -> Self {
+        self.markup(Markup::Latex)
+    }
+
+    /// HTML with powers in superscripts (e.g. `4186&nbsp;J·kg<sup>-1</sup>·K<sup>-1</sup>`)
+    pub fn html(self) -> Self {
+        self.markup(Markup::Html)
+    }
+
+    /// A MathML `<math>` element
+    pub fn mathml(self) -> Self {
+        self.markup(Markup::MathMl)
+    }
+
+    /// Rounds to the precision if there is one, otherwise to the significant figures
+    fn rounded(&self, value: NativeType, precision: Option<usize>) -> NativeType {
+        match precision {
+            Some(precision) => round(value, precision as i32),
+            None => round_significant(value, self.figures),
+        }
+    }
+
+    fn is_scientific(&self, value: NativeType) -> bool {
+        let magnitude = value.abs();
+        value != 0.0 && (magnitude < self.positional.0 || magnitude >= self.positional.1)
+    }
+
+    /// The largest prefix that is a multiple of 3 at or below `value`
+    fn automatic_prefix(value: NativeType) -> Option<Prefix> {
+        if value == 0.0 || !value.is_finite() {
+            return None;
+        }
+        let exponent = (decade(value).div_euclid(3) * 3).clamp(-30, 30);
+        Prefix::from_exponent(exponent)
+    }
+
+    /// The prefix and the value scaled by it
+    fn scaled(&self, precision: Option<usize>) -> (Option<Prefix>, NativeType) {
+        let value = (self.unit.convert)(self.value);
+        if !self.unit.prefixable {
+            return (None, value);
+        }
+        let prefix = match self.scaling {
+            Scaling::None => return (None, value),
+            Scaling::Fixed(prefix) => return (Some(prefix), scale(value, Some(prefix))),
+            Scaling::Automatic => Self::automatic_prefix(value),
+        };
+        let scaled = scale(value, prefix);
+        if self.rounded(scaled, precision).abs() >= 1000.0
+            && let Some(larger) = Prefix::from_exponent(prefix.map_or(0, |p| p.exponent()) + 3)
+        {
+            // 999.96 to one decimal place is shown as 1.0 k rather than 1000.0
+            return (Some(larger), scale(value, Some(larger)));
+        }
+        (prefix, scaled)
+    }
+
+    /// The prefix and unit text after the number
+    fn unit_text(&self, prefix: Option<Prefix>, singular: bool) -> (&str, &str) {
+        match self.style {
+            UnitStyle::Symbol if self.ascii => (
+                prefix.map_or("", |p| p.ascii_symbol()),
+                self.unit.ascii_symbol,
+            ),
+            UnitStyle::Symbol => (prefix.map_or("", |p| p.symbol()), self.unit.symbol),
+            UnitStyle::Name => {
+                let english = if singular {
+                    self.unit.singular
+                } else {
+                    self.unit.plural
+                };
+                let name = self
+                    .translation
+                    .and_then(|translation| translation.unit_name(self.unit.name, !singular))
+                    .unwrap_or(english);
+                (prefix.map_or("", |p| p.name()), name)
+            }
+        }
+    }
+}
+
+/// Divides `value` by the prefix factor (multiplying by the reciprocal prefix
+/// for small prefixes so that e.g. 0.0047 becomes exactly 4.7)
+fn scale(value: NativeType, prefix: Option<Prefix>) -> NativeType {
+    match prefix {
+        None => value,
+        Some(prefix) if prefix.exponent() < 0 => match Prefix::from_exponent(-prefix.exponent()) {
+            Some(reciprocal) => value * reciprocal.factor(),
+            None => value / prefix.factor(),
+        },
+        Some(prefix) => value / prefix.factor(),
+    }
+}
+
+/// A float written with an optional precision, in positional or scientific notation
+struct Number {
+    value: NativeType,
+    precision: Option<usize>,
+    scientific: bool,
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.precision, self.scientific) {
+            (Some(precision), false) => write!(f, "{:.*}", precision, self.value),
+            (Some(precision), true) => write!(f, "{:.*e}", precision, self.value),
+            (None, false) => write!(f, "{}", self.value),
+            (None, true) => write!(f, "{:e}", self.value),
+        }
+    }
+}
+
+impl<T: Quantity> fmt::Display for Formatted<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (prefix, value) = self.scaled(f.precision());
+        let precision = f.precision();
+        let scientific = self.is_scientific(value);
+        let number = Number {
+            // scientific notation rounds the mantissa itself
+            value: match (precision, scientific) {
+                (None, _) => round_significant(value, self.figures),
+                (Some(_), true) => value,
+                (Some(precision), false) => self.rounded(value, Some(precision)),
+            },
+            precision,
+            scientific,
+        };
+        let mut measure = Measure::default();
+        write!(measure, "{}", number)?;
+
+        if self.markup != Markup::Text {
+            render::open(f, self.markup, self.unit.unit)?;
+            let mut output = MarkupNumber::new(f, self.markup)?;
+            write!(
+                Localize::new(&mut output, self.locale, &measure),
+                "{}",
+                number
+            )?;
+            output.finish()?;
+            return render::close(f, self.markup, prefix, self.unit.unit);
+        }
+
+        let (prefix, unit) = self.unit_text(prefix, measure.is_one());
+        let separator = if unit.is_empty() { "" } else { " " };
+        let length = measure.localized_chars(self.locale)
+            + separator.len()
+            + prefix.chars().count()
+            + unit.chars().count();
+        pad(f, length, |f| {
+            write!(Localize::new(f, self.locale, &measure), "{}", number)?;
+            f.write_str(separator)?;
+            f.write_str(prefix)?;
+            f.write_str(unit)
+        })
+    }
+}
+
+/// Serialises the formatted text (e.g. `"10000 feet"`), which parses back
+#[cfg(feature = "serde")]
+impl<T: Quantity> serde::Serialize for Formatted<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<T: Quantity> fmt::Debug for Formatted<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Formatted")
+            .field("value", &self.value)
+            .field("quantity", &T::NAME)
+            .field("style", &self.style)
+            .field("scaling", &self.scaling)
+            .field("ascii", &self.ascii)
+            .field("locale", &self.locale)
+            .field("figures", &self.figures)
+            .field("positional", &self.positional)
+            .field("markup", &self.markup)
+            .field("unit", &self.unit.symbol)
+            .finish()
+    }
+}
+
+/// Enough significant figures to tell any two values apart, which `uDebug` writes
+#[cfg(all(feature = "ufmt", feature = "f32"))]
+const EXACT_FIGURES: i32 = 9;
+#[cfg(all(feature = "ufmt", not(feature = "f32")))]
+const EXACT_FIGURES: i32 = 17;
+
+/// Writes a quantity like its `Display` (long names, rounded to
+/// [`SIGNIFICANT_FIGURES`]) without pulling in `core::fmt` float formatting
+#[cfg(feature = "ufmt")]
+pub(crate) fn write_ufmt<T: Quantity, W: ufmt::uWrite + ?Sized>(
+    value: NativeType,
+    f: &mut ufmt::Formatter<'_, W>,
+) -> Result<(), W::Error> {
+    let singular = round_significant(value.abs(), SIGNIFICANT_FIGURES) == 1.0;
+    write_ufmt_number(value, SIGNIFICANT_FIGURES, f)?;
+    let unit = if singular { T::SINGULAR } else { T::PLURAL };
+    if !unit.is_empty() {
+        f.write_str(" ")?;
+    }
+    f.write_str(unit)
+}
+
+/// Writes the bare value unrounded for `uDebug`
+///
+/// An `f32` reads back exactly; an `f64` can be out by an ulp, as its
+/// seventeenth figure is beyond what the scaling can hold.
+#[cfg(feature = "ufmt")]
+pub(crate) fn write_ufmt_exact<W: ufmt::uWrite + ?Sized>(
+    value: NativeType,
+    f: &mut ufmt::Formatter<'_, W>,
+) -> Result<(), W::Error> {
+    write_ufmt_number(value, EXACT_FIGURES, f)
+}
+
+#[cfg(feature = "ufmt")]
+fn write_ufmt_number<W: ufmt::uWrite + ?Sized>(
+    value: NativeType,
+    figures: i32,
+    f: &mut ufmt::Formatter<'_, W>,
+) -> Result<(), W::Error> {
+    if value < 0.0 {
+        f.write_str("-")?;
+    }
+    let magnitude = value.abs();
+    if magnitude.is_nan() {
+        f.write_str("NaN")
+    } else if magnitude.is_infinite() {
+        f.write_str("inf")
+    } else if magnitude == 0.0 {
+        f.write_str("0")
+    } else {
+        write_ufmt_digits(magnitude, figures, f)
+    }
+}
+
+/// Writes a finite, positive `magnitude` to `figures` significant figures
+/// with integer arithmetic, in scientific notation outside `1e-3..1e9`
+#[cfg(feature = "ufmt")]
+fn write_ufmt_digits<W: ufmt::uWrite + ?Sized>(
+    magnitude: NativeType,
+    figures: i32,
+    f: &mut ufmt::Formatter<'_, W>,
+) -> Result<(), W::Error> {
+    let mut exponent = decade(magnitude);
+    // in f64 so that every figure of an f32 is exact
+    #[cfg(feature = "f32")]
+    let magnitude = magnitude as f64;
+    // in two steps so neither power of ten overflows for subnormal values
+    let half = (figures - 1 - exponent) / 2;
+    let shifted = magnitude
+        * libm::pow(10.0, half as f64)
+        * libm::pow(10.0, (figures - 1 - exponent - half) as f64);
+    let mut digits = libm::round(shifted) as u64;
+    if digits >= 10u64.pow(figures as u32) {
+        digits /= 10;
+        exponent += 1;
+    }
+
+    let mut buffer = [b'0'; EXACT_FIGURES as usize];
+    let significant = &mut buffer[..figures as usize];
+    for digit in significant.iter_mut().rev() {
+        *digit = b'0' + (digits % 10) as u8;
+        digits /= 10;
+    }
+    let count = significant
+        .iter()
+        .rposition(|digit| *digit != b'0')
+        .map_or(1, |last| last + 1);
+    let text =
+        |range: core::ops::Range<usize>| core::str::from_utf8(&significant[range]).unwrap_or("");
+
+    if !(-3..9).contains(&exponent) {
+        f.write_str(text(0..1))?;
+        if count > 1 {
+            f.write_str(".")?;
+            f.write_str(text(1..count))?;
+        }
+        f.write_str("e")?;
+        return ufmt::uDisplay::fmt(&exponent, f);
+    }
+    if exponent < 0 {
+        f.write_str("0.")?;
+        for _ in 1..-exponent {
+            f.write_str("0")?;
+        }
+        return f.write_str(text(0..count));
+    }
+    let integer_digits = exponent as usize + 1;
+    f.write_str(text(0..integer_digits.min(count)))?;
+    for _ in count..integer_digits {
+        f.write_str("0")?;
+    }
+    if count > integer_digits {
+        f.write_str(".")?;
+        f.write_str(text(integer_digits..count))?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Capacitance, ElectricResistance, Length, Temperature};
+
+    /// A fixed-size buffer, as on targets without an allocator
+    struct Buffer {
+        bytes: [u8; 64],
+        length: usize,
+    }
+
+    impl Buffer {
+        fn new() -> Self {
+            Self {
+                bytes: [0; 64],
+                length: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.length]).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, text: &str) -> fmt::Result {
+            let end = self.length + text.len();
+            self.bytes
+                .get_mut(self.length..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(text.as_bytes());
+            self.length = end;
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "ufmt")]
+    impl ufmt::uWrite for Buffer {
+        type Error = fmt::Error;
+
+        fn write_str(&mut self, text: &str) -> fmt::Result {
+            Write::write_str(self, text)
+        }
+    }
+
+    #[test]
+    fn engineering_notation() {
+        let capacitance = Capacitance::new(0.0047);
+        let resistance = ElectricResistance::new(4_700.0);
+        assert_eq!(capacitance.engineering().to_string(), "4.7 mF");
+        assert_eq!(resistance.engineering().to_string(), "4.7 kΩ");
+        assert_eq!(resistance.engineering().names().to_string(), "4.7 kiloohms");
+        assert_eq!(Length::new(0.0).engineering().to_string(), "0 m");
+        assert_eq!(Length::new(-25_000.0).engineering().to_string(), "-25 km");
+        assert_eq!(
+            Temperature::new(1_500.0).engineering().to_string(),
+            "1500 °C"
+        );
+        assert_eq!(
+            format!("{:.3}", Capacitance::new(2.2e-7).engineering()),
+            "220.000 nF"
+        );
+        assert_eq!(
+            format!("{:.1}", Length::new(999.96).engineering()),
+            "1.0 km"
+        );
+        assert_eq!(
+            capacitance.engineering().prefix(Prefix::Micro).to_string(),
+            "4700 µF"
+        );
+    }
+
+    #[test]
+    fn padded_display() {
+        let resistance = ElectricResistance::new(4_700.0);
+        assert_eq!(format!("[{:>8}]", resistance.engineering()), "[  4.7 kΩ]");
+        assert_eq!(format!("[{:<8}]", resistance.engineering()), "[4.7 kΩ  ]");
+        assert_eq!(
+            format!("[{:*^9.2}]", resistance.engineering()),
+            "[*4.70 kΩ*]"
+        );
+        assert_eq!(format!("{:.2}", Length::new(1.0 / 3.0)), "0.33 meters");
+        assert_eq!(format!("[{:>12}]", Length::new(1.5)), "[  1.5 meters]");
+    }
+
+    struct German;
+
+    impl Translate for German {
+        fn unit_name(&self, quantity: &'static str, plural: bool) -> Option<&str> {
+            match (quantity, plural) {
+                ("Length", _) => Some("Meter"),
+                ("Capacitance", _) => Some("Farad"),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn labels_and_locales() {
+        assert_eq!(Length::new(1.0).to_string(), "1 meter");
+        assert_eq!(Length::new(-1.0).to_string(), "-1 meter");
+        assert_eq!(format!("{:.1}", Length::new(1.0)), "1.0 meters");
+        assert_eq!(Length::new(2.0).to_string(), "2 meters");
+        assert_eq!(Temperature::new(1.0).to_string(), "1 degree Celsius");
+        assert_eq!(
+            Temperature::new(21.5).format().symbols().to_string(),
+            "21.5 °C"
+        );
+        assert_eq!(
+            Temperature::new(21.5)
+                .format()
+                .symbols()
+                .ascii()
+                .to_string(),
+            "21.5 degC"
+        );
+        assert_eq!(
+            Capacitance::new(4.7e-6).engineering().ascii().to_string(),
+            "4.7 uF"
+        );
+        assert_eq!(
+            ElectricResistance::new(4_700.0)
+                .engineering()
+                .ascii()
+                .to_string(),
+            "4.7 kOhm"
+        );
+
+        let length = Length::new(1_234_567.5);
+        assert_eq!(
+            format!("{:.2}", length.format().locale(Locale::ENGLISH)),
+            "1,234,567.50 meters"
+        );
+        assert_eq!(
+            format!("{:.2}", length.format().locale(Locale::GERMAN)),
+            "1.234.567,50 meters"
+        );
+        assert_eq!(
+            format!(
+                "[{:>16.1}]",
+                Length::new(-1_234.5)
+                    .format()
+                    .symbols()
+                    .locale(Locale::SWISS)
+            ),
+            "[      -1'234.5 m]"
+        );
+        assert_eq!(
+            format!(
+                "{:.1}",
+                length.format().locale(Locale::GERMAN).translate(&German)
+            ),
+            "1.234.567,5 Meter"
+        );
+        assert_eq!(
+            Capacitance::new(2.0)
+                .format()
+                .translate(&German)
+                .to_string(),
+            "2 Farad"
+        );
+    }
+
+    #[test]
+    fn significant_figures() {
+        use crate::imperial::Feet;
+
+        let feet: Feet = Length::new(2.0).into();
+        assert_eq!(
+            feet.format().significant_figures(4).to_string(),
+            "6.562 feet"
+        );
+        assert_eq!(Length::new(0.1 + 0.2).to_string(), "0.3 meters");
+        assert_eq!(Length::new(2.5e-5).to_string(), "2.5e-5 meters");
+        assert_eq!(format!("{:.2}", Length::new(2.5e-5)), "2.50e-5 meters");
+        assert_eq!(Length::new(3.0e9).to_string(), "3e9 meters");
+        assert_eq!(
+            Length::new(3.0e9).format().positional().to_string(),
+            "3000000000 meters"
+        );
+        assert_eq!(
+            Length::new(0.5).format().scientific().symbols().to_string(),
+            "5e-1 m"
+        );
+        assert_eq!(
+            Length::new(2.5e-5)
+                .format()
+                .scientific_outside(1e-6, 1e6)
+                .to_string(),
+            "0.000025 meters"
+        );
+        assert_eq!(
+            Length::new(999.999_999_999_999_9).engineering().to_string(),
+            "1 km"
+        );
+    }
+
+    #[test]
+    fn fixed_buffer() {
+        let mut buffer = Buffer::new();
+        write!(buffer, "{:.1}", Length::new(1.24)).unwrap();
+        assert_eq!(buffer.as_str(), "1.2 meters");
+
+        let mut buffer = Buffer::new();
+        write!(buffer, "{:?}", Length::new(1.0)).unwrap();
+        assert_eq!(buffer.as_str(), "Length { value: 1.0, label: \"meters\" }");
+
+        // `Debug` isn't rounded like `Display`
+        let value: NativeType = 0.1 + 0.2;
+        let mut buffer = Buffer::new();
+        write!(buffer, "{:?}", Length::new(value)).unwrap();
+        assert_eq!(
+            buffer.as_str(),
+            format!("Length {{ value: {:?}, label: \"meters\" }}", value)
+        );
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn micro_format() {
+        let ufmt = |length: Length| {
+            let mut buffer = Buffer::new();
+            ufmt::uwrite!(buffer, "{}", length).unwrap();
+            buffer.as_str().to_owned()
+        };
+        assert_eq!(ufmt(Length::new(1.0)), "1 meter");
+        assert_eq!(ufmt(Length::new(-0.3)), "-0.3 meters");
+        assert_eq!(ufmt(Length::new(1_500.0)), "1500 meters");
+        assert_eq!(ufmt(Length::new(0.0012)), "0.0012 meters");
+        assert_eq!(ufmt(Length::new(2.5e-5)), "2.5e-5 meters");
+        assert_eq!(ufmt(Length::new(3.0e9)), "3e9 meters");
+        assert_eq!(ufmt(Length::new(0.0)), "0 meters");
+        for value in [1.0 / 3.0, 123_456.0 + 0.789, 0.1 + 0.2] {
+            assert_eq!(ufmt(Length::new(value)), Length::new(value).to_string());
+        }
+
+        let mut buffer = Buffer::new();
+        ufmt::uwrite!(buffer, "{:?}", Length::new(2.0)).unwrap();
+        assert_eq!(buffer.as_str(), "Length { value: 2, label: \"meters\" }");
+        // the value is written with enough figures to read back exactly
+        for value in [0.1 + 0.2, 1.0 / 3.0, -2.0e-7, 123_456.0 + 0.789] {
+            let mut buffer = Buffer::new();
+            ufmt::uwrite!(buffer, "{:?}", Length::new(value)).unwrap();
+            let text = buffer.as_str();
+            let digits = &text["Length { value: ".len()..text.find(',').unwrap()];
+            assert_eq!(digits.parse::<NativeType>().unwrap(), value, "{}", text);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        fn check<T>(value: T)
+        where
+            T: Quantity + PartialEq + core::fmt::Debug + core::str::FromStr,
+            T::Err: core::fmt::Debug,
+        {
+            let texts = [
+                Formatted::new(value).to_string(),
+                Formatted::new(value).symbols().to_string(),
+                Formatted::new(value).symbols().ascii().to_string(),
+                Formatted::new(value).engineering().to_string(),
+                Formatted::new(value).engineering().names().to_string(),
+                Formatted::new(value).scientific().to_string(),
+            ];
+            for text in texts {
+                assert_eq!(text.parse::<T>().unwrap(), value, "{}", text);
+            }
+        }
+
+        // 2 m in feet, which has no short decimal form
+        check(Length::new(2.0 / 0.3048));
+        check(ElectricResistance::new(4_700.0));
+        check(Capacitance::new(2.5e-5));
+        check(Temperature::new(-40.0));
+        check(Length::new(1.0));
+    }
+}
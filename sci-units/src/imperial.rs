@@ -5,7 +5,7 @@ use bincode::{Decode, Encode};
 
 use crate::{
     Acceleration, AngularAcceleration, AngularVelocity, Area, Force, Length, Mass, PlaneAngle,
-    Pressure, Temperature, ThermodynamicTemperature, Torque, Velocity, Volume,
+    Pressure, Scalar, Temperature, ThermodynamicTemperature, Torque, Velocity, Volume,
 };
 
 const ZERO_OFFSET: NativeType = 0.0;
@@ -20,6 +20,7 @@ const GALLONS_PER_METER_CUBED: NativeType = 1.0 / 0.004_546_09;
 const DEGREES_PER_RADIAN: NativeType = 180.0 / crate::PI;
 const FAHRENHEIT_PER_CELCIUS: NativeType = 9.0 / 5.0;
 const FAHRENHEIT_OFFSET: NativeType = 32.0;
+const ABSOLUTE_ZERO_FAHRENHEIT: NativeType = -459.67;
 const RANKIN_PER_KELVIN: NativeType = 9.0 / 5.0;
 const REVOLUTIONS_PER_RADIAN: NativeType = 1.0 / (2.0 * crate::PI);
 const REVOLUTIONS_PER_RADIAN_SECONDS_PER_MINUTE: NativeType = REVOLUTIONS_PER_RADIAN * 60.0;
@@ -139,6 +140,44 @@ pub struct Degrees {
     native: NativeType,
 }
 
+impl Degrees {
+    fn radians(&self) -> PlaneAngle {
+        (*self).into()
+    }
+
+    pub fn sin(&self) -> Scalar {
+        self.radians().sin()
+    }
+
+    pub fn cos(&self) -> Scalar {
+        self.radians().cos()
+    }
+
+    pub fn tan(&self) -> Scalar {
+        self.radians().tan()
+    }
+
+    /// Returns `(sin, cos)`
+    pub fn sin_cos(&self) -> (Scalar, Scalar) {
+        self.radians().sin_cos()
+    }
+
+    /// Wraps the angle to `[0, 360)`
+    pub fn normalize(&self) -> Self {
+        Self::from(self.radians().normalize())
+    }
+
+    /// Wraps the angle to `(-180, 180]`
+    pub fn normalize_signed(&self) -> Self {
+        Self::from(self.radians().normalize_signed())
+    }
+
+    /// The smallest signed rotation that takes `other` to `self`, in `(-180, 180]`
+    pub fn shortest_difference(&self, other: Degrees) -> Self {
+        Self::from(self.radians().shortest_difference(other.into()))
+    }
+}
+
 #[derive(
     Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, SiConvert, Decode, Encode,
 )]
@@ -158,7 +197,13 @@ pub struct DegreesPerSecondSquared {
 #[derive(
     Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, SiConvert, Decode, Encode,
 )]
-#[parameters(multiplier = FAHRENHEIT_PER_CELCIUS, offset = FAHRENHEIT_OFFSET, into = Temperature)]
+#[parameters(
+    multiplier = FAHRENHEIT_PER_CELCIUS,
+    offset = FAHRENHEIT_OFFSET,
+    into = Temperature,
+    minimum = ABSOLUTE_ZERO_FAHRENHEIT,
+    minimum_error = BelowAbsoluteZero
+)]
 pub struct DegreesFahrenheit {
     native: NativeType,
 }
@@ -166,7 +211,13 @@ pub struct DegreesFahrenheit {
 #[derive(
     Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, SiConvert, Decode, Encode,
 )]
-#[parameters(multiplier = RANKIN_PER_KELVIN, offset = ZERO_OFFSET, into = ThermodynamicTemperature)]
+#[parameters(
+    multiplier = RANKIN_PER_KELVIN,
+    offset = ZERO_OFFSET,
+    into = ThermodynamicTemperature,
+    minimum = ZERO_OFFSET,
+    minimum_error = BelowAbsoluteZero
+)]
 pub struct DegreesRankine {
     native: NativeType,
 }
@@ -238,7 +289,13 @@ pub struct PoundsForce {
 #[derive(
     Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, SiConvert, Decode, Encode,
 )]
-#[parameters(multiplier = POUNDS_PER_KILOGRAM, offset = ZERO_OFFSET, into = Mass)]
+#[parameters(
+    multiplier = POUNDS_PER_KILOGRAM,
+    offset = ZERO_OFFSET,
+    into = Mass,
+    minimum = ZERO_OFFSET,
+    minimum_error = Negative
+)]
 pub struct Pounds {
     native: NativeType,
 }
@@ -246,7 +303,13 @@ pub struct Pounds {
 #[derive(
     Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, SiConvert, Decode, Encode,
 )]
-#[parameters(multiplier = OUNCES_PER_KILOGRAM, offset = ZERO_OFFSET, into = Mass)]
+#[parameters(
+    multiplier = OUNCES_PER_KILOGRAM,
+    offset = ZERO_OFFSET,
+    into = Mass,
+    minimum = ZERO_OFFSET,
+    minimum_error = Negative
+)]
 pub struct Ounces {
     native: NativeType,
 }
@@ -326,7 +389,7 @@ mod tests {
 
         assert_eq!(
             Feet::from(crate::Length::from(2.0 as NativeType)),
-            Feet::from(6.561679790026246 as NativeType)
+            Feet::from(2.0 / 0.3048 as NativeType)
         );
 
         assert_eq!(
@@ -359,4 +422,18 @@ mod tests {
 
         assert_eq!(G::from(crate::constants::g), G::from(1.0 as NativeType));
     }
+
+    #[test]
+    fn degrees_trigonometry_test() {
+        assert_eq!(Degrees::new(30.0).sin(), Scalar::new(0.5));
+        assert_eq!(Degrees::new(60.0).cos(), Scalar::new(0.5));
+        assert_eq!(Degrees::new(60.0).tan(), Scalar::new(3.0).sqrt());
+        assert_eq!(Degrees::new(-90.0).normalize(), Degrees::new(270.0));
+        assert_eq!(Degrees::new(-270.0).normalize_signed(), Degrees::new(90.0));
+        assert_eq!(
+            Degrees::new(10.0).shortest_difference(Degrees::new(350.0)),
+            Degrees::new(20.0)
+        );
+        assert_eq!(Degrees::from(Scalar::new(0.5).asin()), Degrees::new(30.0));
+    }
 }
@@ -3,13 +3,43 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod bounded;
+pub mod calculus;
+pub mod decibel;
+pub mod error;
+pub mod filters;
+pub mod format;
 pub mod imperial;
+pub mod lookup;
+pub mod ode;
+pub mod parse;
+pub mod pid;
+pub mod polynomial;
+pub mod prefix;
+pub mod render;
+#[cfg(feature = "serde")]
+pub mod representation;
+pub mod solver;
+pub mod statistics;
+pub mod system;
 mod test;
 
+pub use bounded::{Bounded, Limits};
+pub use decibel::{
+    Bel, Decade, Decibel, DecibelReference, DecibelType, DecibelsPerDecade, DecibelsPerOctave,
+    Neper, Octave,
+};
+pub use error::{UnitError, UnitErrorKind};
+pub use format::{Formatted, Locale, Scaling, Translate, UnitStyle};
+pub use parse::{ParseError, ParseErrorKind};
+pub use prefix::Prefix;
+pub use render::{Factor, Markup};
+pub use system::{DisplayUnit, Preference, UnitSystem};
+
 use bincode::{Decode, Encode};
 use sci_units_proc_macro::{
-    SiAddSubtract, SiConvert, SiDisplay, SiDivide, SiInvert, SiMultiply, SiMultiplyDivideScalar,
-    SiSquare,
+    SiAddSubtract, SiConvert, SiCube, SiDisplay, SiDivide, SiInvert, SiMultiply,
+    SiMultiplyDivideScalar, SiSquare,
 };
 
 // These are used with the macros in units-proc-macro
@@ -31,156 +61,444 @@ pub const SIGNIFICANT_FIGURES: i32 = 12;
 
 const ZERO_OFFSET: NativeType = 0.0;
 const LITERS_PER_METER_CUBED: NativeType = 1_000.0;
+const ABSOLUTE_ZERO_CELSIUS: NativeType = -273.15;
+
+/// Implemented by every unit type (derived with `SiAddSubtract`)
+pub trait Quantity: Copy + Into<NativeType> + From<NativeType> {
+    /// The name of the type (e.g. `"ElectricPotential"`)
+    const NAME: &'static str;
+    /// The unit label used when displaying the value (e.g. `"volts"`)
+    const LABEL: &'static str;
+    /// Other names accepted when parsing and deserialising, such as old
+    /// labels and alternative spellings (e.g. `"celcius"`, `"metres"`)
+    const ALIASES: &'static [&'static str];
+    /// The unit symbol (e.g. `"V"`)
+    const SYMBOL: &'static str;
+    /// The unit symbol without unicode characters (e.g. `"Ohm"` for `"Ω"`)
+    const ASCII_SYMBOL: &'static str;
+    /// The unit as symbols raised to powers (e.g. `J`, `kg⁻¹`, `K⁻¹`)
+    const UNIT: &'static [render::Factor];
+    /// The long name of one unit (e.g. `"volt"`)
+    const SINGULAR: &'static str;
+    /// The long name of several units (e.g. `"volts"`)
+    const PLURAL: &'static str;
+    /// `true` if SI prefixes can be put in front of the symbol (e.g. `"kV"`)
+    const PREFIXABLE: bool;
+
+    /// Checks the value is finite and within the physical domain of the quantity
+    fn validate(self) -> Result<Self, UnitError>;
+}
+
+/// Implemented by quantities whose square is a named type (derived with `SiSquare`)
+pub trait Squared: Quantity {
+    type Output: Quantity + SquareRoot<Output = Self>;
+    fn square(&self) -> Self::Output;
+}
+
+/// Implemented by squared quantities (derived with `SiSquare`)
+pub trait SquareRoot: Quantity {
+    type Output: Quantity;
+    fn sqrt(&self) -> Self::Output;
+}
+
+/// Implemented by quantities whose cube is a named type (derived with `SiCube`)
+pub trait Cubed: Quantity {
+    type Output: Quantity + CubeRoot<Output = Self>;
+    fn cube(&self) -> Self::Output;
+}
+
+/// Implemented by cubed quantities (derived with `SiCube`)
+pub trait CubeRoot: Quantity {
+    type Output: Quantity;
+    fn cbrt(&self) -> Self::Output;
+}
 
-#[derive(Copy, Clone, Decode, Encode)]
-pub enum DecibelType {
-    Power,
-    Signal,
+// Unitless
+#[derive(Copy, Clone, SiAddSubtract, SiDisplay, Decode, Encode)]
+pub struct Scalar {
+    native: NativeType,
 }
 
-impl From<DecibelType> for NativeType {
-    fn from(val: DecibelType) -> Self {
-        match val {
-            DecibelType::Power => 10.0,
-            DecibelType::Signal => 20.0,
+impl Scalar {
+    pub fn sqrt(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::sqrtf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::sqrt(self.native))
         }
     }
-}
 
-#[derive(Copy, Clone, Decode, Encode)]
-pub struct Decibel<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>> {
-    value: NativeType, //dB ratio value
-    reference: UnitType,
-    multiplier: DecibelType,
-}
+    /// The four-quadrant arctangent of `self / other` in radians, as a `Scalar`
+    ///
+    /// Other quantities return a `PlaneAngle`; this keeps the original signature.
+    pub fn atan2(&self, other: Scalar) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::atan2f(self.native, other.native))
+        }
 
-impl<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>>
-    Decibel<UnitType>
-{
-    pub fn new_from_raw(value: UnitType, reference: UnitType, multiplier: DecibelType) -> Self {
-        let ratio: NativeType = value.into() / reference.into();
-        let mult: NativeType = multiplier.into();
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::atan2(self.native, other.native))
+        }
+    }
 
+    pub fn asin(&self) -> PlaneAngle {
         #[cfg(feature = "f32")]
-        let value = mult * libm::log10f(ratio);
+        {
+            PlaneAngle::from(libm::asinf(self.native))
+        }
+
         #[cfg(not(feature = "f32"))]
-        let value = mult * libm::log10(ratio);
+        {
+            PlaneAngle::from(libm::asin(self.native))
+        }
+    }
 
-        Self {
-            value,
-            reference,
-            multiplier,
+    pub fn acos(&self) -> PlaneAngle {
+        #[cfg(feature = "f32")]
+        {
+            PlaneAngle::from(libm::acosf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            PlaneAngle::from(libm::acos(self.native))
         }
     }
 
-    pub fn new(ratio: NativeType, reference: UnitType, multiplier: DecibelType) -> Self {
-        Self {
-            value: ratio,
-            reference,
-            multiplier,
+    pub fn atan(&self) -> PlaneAngle {
+        #[cfg(feature = "f32")]
+        {
+            PlaneAngle::from(libm::atanf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            PlaneAngle::from(libm::atan(self.native))
         }
     }
 
-    pub fn ratio(&self) -> Scalar {
-        let mult: NativeType = self.multiplier.into();
+    pub fn log(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::logf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::log(self.native))
+        }
+    }
 
+    pub fn exp(&self) -> Self {
         #[cfg(feature = "f32")]
         {
-            Scalar::from(libm::powf(10.0, self.value / mult))
+            Self::from(libm::expf(self.native))
         }
+
         #[cfg(not(feature = "f32"))]
         {
-            Scalar::from(libm::pow(10.0, self.value / mult))
+            Self::from(libm::exp(self.native))
         }
     }
 
-    pub fn to_units(&self) -> UnitType {
-        self.reference * self.ratio()
+    pub fn exp2(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::exp2f(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::exp2(self.native))
+        }
     }
-}
 
-impl<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>>
-    core::ops::Add<Decibel<UnitType>> for Decibel<UnitType>
-{
-    type Output = Decibel<UnitType>;
+    /// `e^x - 1`, accurate for small values
+    pub fn exp_m1(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::expm1f(self.native))
+        }
 
-    fn add(self, rhs: Decibel<UnitType>) -> Self::Output {
-        let value = self.value + rhs.value;
-        Self {
-            value,
-            reference: self.reference,
-            multiplier: self.multiplier,
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::expm1(self.native))
         }
     }
-}
 
-impl<UnitType: Into<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>>
-    core::ops::Sub<Decibel<UnitType>> for Decibel<UnitType>
-{
-    type Output = Decibel<UnitType>;
+    /// `ln(1 + x)`, accurate for small values
+    pub fn ln_1p(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::log1pf(self.native))
+        }
 
-    fn sub(self, rhs: Decibel<UnitType>) -> Self::Output {
-        let value = self.value - rhs.value;
-        Self {
-            value,
-            reference: self.reference,
-            multiplier: self.multiplier,
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::log1p(self.native))
         }
     }
-}
 
-impl<
-    UnitType: Into<NativeType> + From<NativeType> + Copy + core::ops::Mul<Scalar, Output = UnitType>,
-> core::ops::Mul<UnitType> for Decibel<UnitType>
-{
-    type Output = UnitType;
+    pub fn log10(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::log10f(self.native))
+        }
 
-    fn mul(self, rhs: UnitType) -> UnitType {
-        UnitType::from(self.ratio().native * rhs.into())
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::log10(self.native))
+        }
     }
-}
 
-// Unitless
-#[derive(Copy, Clone, SiAddSubtract, SiDisplay, Decode, Encode)]
-pub struct Scalar {
-    native: NativeType,
-}
+    pub fn log2(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::log2f(self.native))
+        }
 
-impl Scalar {
-    pub fn sqrt(&self) -> Self {
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::log2(self.native))
+        }
+    }
+
+    pub fn cbrt(&self) -> Self {
         #[cfg(feature = "f32")]
         {
-            Self::from(libm::sqrtf(self.native))
+            Self::from(libm::cbrtf(self.native))
         }
 
         #[cfg(not(feature = "f32"))]
         {
-            Self::from(libm::sqrt(self.native))
+            Self::from(libm::cbrt(self.native))
         }
     }
 
-    pub fn atan2(&self, other: Scalar) -> Self {
+    pub fn sinh(&self) -> Self {
         #[cfg(feature = "f32")]
         {
-            Self::from(libm::atan2f(self.native, other.native))
+            Self::from(libm::sinhf(self.native))
         }
 
         #[cfg(not(feature = "f32"))]
         {
-            Self::from(libm::atan2(self.native, other.native))
+            Self::from(libm::sinh(self.native))
         }
     }
 
-    pub fn log(&self) -> Self {
+    pub fn cosh(&self) -> Self {
         #[cfg(feature = "f32")]
         {
-            Self::from(libm::logf(self.native))
+            Self::from(libm::coshf(self.native))
         }
 
         #[cfg(not(feature = "f32"))]
         {
-            Self::from(libm::log(self.native))
+            Self::from(libm::cosh(self.native))
+        }
+    }
+
+    pub fn tanh(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::tanhf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::tanh(self.native))
+        }
+    }
+
+    pub fn asinh(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::asinhf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::asinh(self.native))
+        }
+    }
+
+    pub fn acosh(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::acoshf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::acosh(self.native))
+        }
+    }
+
+    pub fn atanh(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::atanhf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::atanh(self.native))
+        }
+    }
+
+    pub fn floor(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::floorf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::floor(self.native))
+        }
+    }
+
+    pub fn ceil(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::ceilf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::ceil(self.native))
+        }
+    }
+
+    pub fn round(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::roundf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::round(self.native))
+        }
+    }
+
+    pub fn trunc(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::truncf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::trunc(self.native))
+        }
+    }
+
+    pub fn erf(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::erff(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::erf(self.native))
+        }
+    }
+
+    pub fn erfc(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::erfcf(self.native))
         }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::erfc(self.native))
+        }
+    }
+
+    pub fn tgamma(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::tgammaf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::tgamma(self.native))
+        }
+    }
+
+    pub fn lgamma(&self) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::lgammaf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::lgamma(self.native))
+        }
+    }
+
+    pub fn powf(&self, exponent: Scalar) -> Self {
+        #[cfg(feature = "f32")]
+        {
+            Self::from(libm::powf(self.native, exponent.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Self::from(libm::pow(self.native, exponent.native))
+        }
+    }
+
+    pub fn powi(&self, exponent: i32) -> Self {
+        self.powf(Scalar::from(exponent as NativeType))
+    }
+
+    pub fn square(&self) -> Self {
+        Self::from(self.native * self.native)
+    }
+
+    pub fn cube(&self) -> Self {
+        Self::from(self.native * self.native * self.native)
+    }
+}
+
+impl Squared for Scalar {
+    type Output = Scalar;
+    fn square(&self) -> Scalar {
+        Scalar::square(self)
+    }
+}
+
+impl SquareRoot for Scalar {
+    type Output = Scalar;
+    fn sqrt(&self) -> Scalar {
+        Scalar::sqrt(self)
+    }
+}
+
+impl Cubed for Scalar {
+    type Output = Scalar;
+    fn cube(&self) -> Scalar {
+        Scalar::cube(self)
+    }
+}
+
+impl CubeRoot for Scalar {
+    type Output = Scalar;
+    fn cbrt(&self) -> Scalar {
+        Scalar::cbrt(self)
     }
 }
 
@@ -208,9 +526,17 @@ pub struct Area {
 }
 
 #[derive(
-    Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiMultiply, SiDisplay, Decode, Encode,
+    Copy,
+    Clone,
+    SiAddSubtract,
+    SiMultiplyDivideScalar,
+    SiMultiply,
+    SiCube,
+    SiDisplay,
+    Decode,
+    Encode,
 )]
-#[parameters(lhs_mult = Area, rhs_mult = Length)]
+#[parameters(lhs_mult = Area, rhs_mult = Length, cube = Length)]
 pub struct Volume {
     native: NativeType,
 }
@@ -223,6 +549,14 @@ pub struct Liters {
     native: NativeType,
 }
 
+#[derive(
+    Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDivide, SiDisplay, Decode, Encode,
+)]
+#[parameters(lhs_div = Volume, rhs_div = Time)]
+pub struct VolumetricFlow {
+    native: NativeType,
+}
+
 #[derive(Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, Decode, Encode)]
 pub struct OrthogonalLength {
     native: NativeType,
@@ -256,6 +590,7 @@ pub struct FrequencySquared {
 }
 
 #[derive(Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, Decode, Encode)]
+#[parameters(minimum = ZERO_OFFSET, minimum_error = Negative)]
 pub struct Mass {
     native: NativeType,
 }
@@ -263,7 +598,7 @@ pub struct Mass {
 #[derive(
     Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDivide, SiDisplay, Decode, Encode,
 )]
-#[parameters(lhs_div = Mass, rhs_div = Volume)]
+#[parameters(lhs_div = Mass, rhs_div = Volume, minimum = ZERO_OFFSET, minimum_error = Negative)]
 pub struct MassDensity {
     native: NativeType,
 }
@@ -297,6 +632,60 @@ impl PlaneAngle {
             Scalar::from(libm::cos(self.native))
         }
     }
+
+    pub fn tan(&self) -> Scalar {
+        #[cfg(feature = "f32")]
+        {
+            Scalar::from(libm::tanf(self.native))
+        }
+
+        #[cfg(not(feature = "f32"))]
+        {
+            Scalar::from(libm::tan(self.native))
+        }
+    }
+
+    /// Returns `(sin, cos)`
+    pub fn sin_cos(&self) -> (Scalar, Scalar) {
+        #[cfg(feature = "f32")]
+        let (sin, cos) = libm::sincosf(self.native);
+        #[cfg(not(feature = "f32"))]
+        let (sin, cos) = libm::sincos(self.native);
+
+        (Scalar::from(sin), Scalar::from(cos))
+    }
+
+    /// Wraps the angle to `[0, 2π)`
+    pub fn normalize(&self) -> Self {
+        #[cfg(feature = "f32")]
+        let mut native = libm::fmodf(self.native, 2.0 * PI);
+        #[cfg(not(feature = "f32"))]
+        let mut native = libm::fmod(self.native, 2.0 * PI);
+
+        if native < 0.0 {
+            native += 2.0 * PI;
+        }
+        // rounding can push a tiny negative angle up to exactly 2π
+        if native >= 2.0 * PI {
+            native = 0.0;
+        }
+        Self::from(native)
+    }
+
+    /// Wraps the angle to `(-π, π]`
+    pub fn normalize_signed(&self) -> Self {
+        let native = self.normalize().native;
+        if native > PI {
+            Self::from(native - 2.0 * PI)
+        } else {
+            Self::from(native)
+        }
+    }
+
+    /// The smallest signed rotation that takes `other` to `self`, in `(-π, π]`
+    pub fn shortest_difference(&self, other: PlaneAngle) -> Self {
+        (*self - other).normalize_signed()
+    }
 }
 
 #[derive(
@@ -416,9 +805,9 @@ pub struct ElectricCurrent {
 }
 
 #[derive(
-    Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDivide, SiDisplay, Decode, Encode,
+    Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiMultiply, SiDisplay, Decode, Encode,
 )]
-#[parameters(lhs_div = ElectricCurrent, rhs_div = Time)]
+#[parameters(lhs_mult = ElectricCurrent, rhs_mult = Time)]
 pub struct ElectricCharge {
     native: NativeType,
 }
@@ -469,11 +858,13 @@ pub struct Inductance {
 }
 
 #[derive(Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, Decode, Encode)]
+#[parameters(minimum = ZERO_OFFSET, minimum_error = BelowAbsoluteZero)]
 pub struct ThermodynamicTemperature {
     native: NativeType,
 }
 
 #[derive(Copy, Clone, SiAddSubtract, SiMultiplyDivideScalar, SiDisplay, Decode, Encode)]
+#[parameters(minimum = ABSOLUTE_ZERO_CELSIUS, minimum_error = BelowAbsoluteZero)]
 pub struct Temperature {
     native: NativeType,
 }
@@ -757,6 +1148,8 @@ mod tests {
     multiply!(test_multiply_area, Area, Length, Length);
     basic!(test_volume, Volume);
     multiply!(test_multiply_volume, Volume, Area, Length);
+    basic!(test_volumetric_flow, VolumetricFlow);
+    divide!(test_divide_volumetric_flow, VolumetricFlow, Volume, Time);
     basic!(test_orthogonal_length, OrthogonalLength);
     basic!(test_time, Time);
     basic!(test_frequency, Frequency);
@@ -803,8 +1196,8 @@ mod tests {
     );
     basic!(test_electric_current, ElectricCurrent);
     basic!(test_electric_charge, ElectricCharge);
-    divide!(
-        test_divide_electric_charge,
+    multiply!(
+        test_multiply_electric_charge,
         ElectricCharge,
         ElectricCurrent,
         Time
@@ -1041,6 +1434,135 @@ mod tests {
         assert_eq!(db.to_units(), ElectricPotential::new(50.));
     }
 
+    #[test]
+    fn decibel_display() {
+        let gain = Decibel::new(-3.0103, Scalar::new(1.0), DecibelType::Power);
+        assert_eq!(format!("{:.2}", gain), "-3.01 dB");
+        let level = Decibel::new(12.0, Power::new(0.001), DecibelType::Power);
+        assert_eq!(level.to_string(), "12 dBm");
+        let level = Decibel::new(6.0, ElectricPotential::new(1.0), DecibelType::Signal);
+        assert_eq!(level.to_string(), "6 dBV");
+        let level = Decibel::new(94.0, constants::p0, DecibelType::Signal);
+        assert_eq!(level.to_string(), "94 dB SPL");
+        let level = Decibel::new(3.0, Power::new(2.0), DecibelType::Power);
+        assert_eq!(level.to_string(), "3 dB re 2 watts");
+
+        let json = serde_json::to_string(&level).unwrap();
+        assert_eq!(
+            json,
+            r#"{"value":3.0,"reference":"2_watts","multiplier":"Power"}"#
+        );
+        let restored: Decibel<Power> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.to_string(), "3 dB re 2 watts");
+        assert_eq!(restored.multiplier(), DecibelType::Power);
+        assert!(serde_json::from_str::<Decibel<ElectricPotential>>(&json).is_err());
+    }
+
+    #[test]
+    fn checked_operations() {
+        assert_eq!(Mass::try_new(1.0), Ok(Mass::new(1.0)));
+        let error = Mass::try_new(-1.0).unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::Negative);
+        assert_eq!(error.quantity(), "Mass");
+        assert_eq!(error.to_string(), "Mass: -1 kilograms is negative");
+
+        let error = ThermodynamicTemperature::new(10.0)
+            .checked_sub(ThermodynamicTemperature::new(20.0))
+            .unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::BelowAbsoluteZero);
+        assert!(Temperature::try_new(-300.0).is_err());
+        assert!(Temperature::try_new(-40.0).is_ok());
+        assert!(Length::try_new(NativeType::NAN).is_err());
+        assert!(Length::new(-1.0).checked_add(Length::new(0.5)).is_ok());
+
+        let current: Result<ElectricCurrent, UnitError> =
+            ElectricPotential::new(5.0).checked_div(ElectricResistance::new(0.0));
+        let error = current.unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::NonFinite);
+        assert_eq!(error.quantity(), "ElectricCurrent");
+        let power: Power = ElectricCurrent::new(2.0)
+            .checked_mul(ElectricPotential::new(3.0))
+            .unwrap();
+        assert_eq!(power, Power::new(6.0));
+
+        let db = Decibel::try_new_from_raw(Power::new(-5.), Power::new(1.), DecibelType::Power);
+        assert_eq!(db.err().map(|e| e.kind()), Some(UnitErrorKind::Negative));
+        let db = Decibel::try_new_from_raw(Power::new(0.), Power::new(1.), DecibelType::Power);
+        assert_eq!(db.err().map(|e| e.kind()), Some(UnitErrorKind::NonFinite));
+        assert!(
+            Decibel::try_new_from_raw(Power::new(5.), Power::new(1.), DecibelType::Power).is_ok()
+        );
+    }
+
+    #[test]
+    fn trigonometry_operations() {
+        let angle = PlaneAngle::new(PI / 4.0);
+        assert_eq!(PlaneAngle::new(PI / 3.0).tan(), Scalar::new(3.0).sqrt());
+        let (sin, cos) = angle.sin_cos();
+        assert_eq!(sin, angle.sin());
+        assert_eq!(cos, angle.cos());
+
+        assert_eq!(Scalar::new(1.0).asin(), PlaneAngle::new(PI / 2.0));
+        assert_eq!(Scalar::new(-1.0).acos(), PlaneAngle::new(PI));
+        assert_eq!(Scalar::new(1.0).atan(), angle);
+        assert_eq!(Length::new(3.0).atan2(Length::new(3.0)), angle);
+        assert_eq!(Length::new(3.0).hypot(Length::new(4.0)), Length::new(5.0));
+        assert_eq!(
+            Scalar::new(1.0).atan2(Scalar::new(1.0)),
+            Scalar::new(PI / 4.0)
+        );
+        assert_eq!(
+            imperial::Feet::new(3.0).atan2(imperial::Feet::new(3.0)),
+            angle
+        );
+        assert_eq!(
+            imperial::Feet::new(3.0).hypot(imperial::Feet::new(4.0)),
+            imperial::Feet::new(5.0)
+        );
+
+        assert_eq!(
+            PlaneAngle::new(-PI / 2.0).normalize(),
+            PlaneAngle::new(3.0 * PI / 2.0)
+        );
+        assert_eq!(
+            PlaneAngle::new(-7.0 * PI / 2.0).normalize_signed(),
+            PlaneAngle::new(PI / 2.0)
+        );
+        assert_eq!(PlaneAngle::new(-PI).normalize_signed(), PlaneAngle::new(PI));
+        assert_eq!(
+            PlaneAngle::new(0.1).shortest_difference(PlaneAngle::new(2.0 * PI - 0.1)),
+            PlaneAngle::new(0.2)
+        );
+    }
+
+    #[test]
+    fn power_operations() {
+        let x = Scalar::new(2.0);
+        assert_eq!(x.exp().log(), x);
+        assert_eq!(x.powf(Scalar::new(3.0)), Scalar::new(8.0));
+        assert_eq!(x.powi(-2), Scalar::new(0.25));
+        assert_eq!(Scalar::new(1000.0).log10(), Scalar::new(3.0));
+        assert_eq!(Scalar::new(8.0).log2(), Scalar::new(3.0));
+        assert_eq!(x.sinh().asinh(), x);
+        assert_eq!(x.tanh(), x.sinh() / x.cosh());
+        assert_eq!(Scalar::new(27.0).cbrt(), Scalar::new(3.0));
+
+        let side = Length::new(3.0);
+        assert_eq!(side.square(), Area::new(9.0));
+        assert_eq!(side.cube(), Volume::new(27.0));
+        assert_eq!(Volume::new(27.0).cbrt(), side);
+        assert_eq!(Area::new(9.0).sqrt(), side);
+        assert_eq!(Velocity::new(3.0).square(), VelocitySquared::new(9.0));
+
+        fn diagonal<T: Squared>(a: T, b: T) -> T
+        where
+            T::Output: core::ops::Add<Output = T::Output>,
+        {
+            (a.square() + b.square()).sqrt()
+        }
+        assert_eq!(diagonal(side, Length::new(4.0)), Length::new(5.0));
+    }
+
     #[test]
     fn readme_test() {
         let length = Length::new(32.0);
@@ -1111,4 +1633,13 @@ mod tests {
         assert_eq!(values.current, deserde_values.current);
         assert_eq!(values.charge, deserde_values.charge);
     }
+
+    #[test]
+    fn renamed_label() {
+        let temperature = Temperature::new(20.0);
+        let json = serde_json::to_string(&temperature).unwrap();
+        assert_eq!(json, "\"20_celsius\"");
+        let restored: Temperature = serde_json::from_str("\"20_celcius\"").unwrap();
+        assert_eq!(restored, temperature);
+    }
 }
@@ -0,0 +1,387 @@
+use crate::{NativeType, Quantity, UnitError, UnitErrorKind};
+
+/// How values between two table points are calculated
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Straight line between neighbouring points
+    Linear,
+    /// Monotone cubic Hermite spline (Fritsch–Carlson)
+    ///
+    /// Passes smoothly through every point without overshooting them, so the
+    /// curve is increasing or decreasing wherever the points are.
+    Cubic,
+    /// The value of the closest point
+    Nearest,
+}
+
+/// What happens when looking up a value outside of the table
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// Return an `OutOfRange` error
+    Error,
+    /// Use the value at the nearest end of the table
+    Clamp,
+    /// Extend the line through the first or last two points
+    Linear,
+}
+
+/// A table of `(X, Y)` points sorted by strictly increasing `X`
+///
+/// The points are borrowed so a table can be built in a `const` or `static`.
+#[derive(Copy, Clone)]
+pub struct LookupTable<'a, X, Y> {
+    points: &'a [(X, Y)],
+    interpolation: Interpolation,
+    extrapolation: Extrapolation,
+}
+
+impl<'a, X: Quantity, Y: Quantity> LookupTable<'a, X, Y> {
+    /// Panics if `points` has fewer than two entries
+    ///
+    /// The `X` values must be strictly increasing. Quantities can't be
+    /// compared in a `const fn`, so this isn't checked; use `try_new` to check
+    /// the order once when the table is built.
+    pub const fn new(
+        points: &'a [(X, Y)],
+        interpolation: Interpolation,
+        extrapolation: Extrapolation,
+    ) -> Self {
+        assert!(
+            points.len() >= 2,
+            "a lookup table needs at least two points"
+        );
+        Self {
+            points,
+            interpolation,
+            extrapolation,
+        }
+    }
+
+    /// Like `new`, but returns a `NotIncreasing` error for the first `X` that
+    /// isn't greater than the one before it
+    pub fn try_new(
+        points: &'a [(X, Y)],
+        interpolation: Interpolation,
+        extrapolation: Extrapolation,
+    ) -> Result<Self, UnitError> {
+        let table = Self::new(points, interpolation, extrapolation);
+        match table.first_unsorted() {
+            Some(index) => Err(UnitError::new::<X>(
+                table.x(index),
+                UnitErrorKind::NotIncreasing,
+            )),
+            None => Ok(table),
+        }
+    }
+
+    /// The index of the first point whose `X` isn't greater than the previous one
+    fn first_unsorted(&self) -> Option<usize> {
+        (1..self.points.len()).find(|&index| {
+            self.x(index).partial_cmp(&self.x(index - 1)) != Some(core::cmp::Ordering::Greater)
+        })
+    }
+
+    pub fn points(&self) -> &'a [(X, Y)] {
+        self.points
+    }
+
+    fn x(&self, index: usize) -> NativeType {
+        self.points[index].0.into()
+    }
+
+    fn y(&self, index: usize) -> NativeType {
+        self.points[index].1.into()
+    }
+
+    fn slope(&self, start: usize) -> NativeType {
+        (self.y(start + 1) - self.y(start)) / (self.x(start + 1) - self.x(start))
+    }
+
+    /// The Fritsch–Carlson tangent at a point, limited so that neither
+    /// neighbouring segment overshoots
+    fn tangent(&self, index: usize) -> NativeType {
+        let last = self.points.len() - 1;
+        if index == 0 {
+            return self.slope(0);
+        } else if index == last {
+            return self.slope(last - 1);
+        }
+        let (before, after) = (self.slope(index - 1), self.slope(index));
+        // flat at a peak, a trough or next to a flat segment
+        if before * after <= 0.0 {
+            return 0.0;
+        }
+        // keeping the tangent within three times each secant keeps both
+        // segments monotone
+        let tangent = (before + after) * 0.5;
+        let limit = 3.0 * before.abs().min(after.abs());
+        tangent.clamp(-limit, limit)
+    }
+
+    /// Interpolates within the segment `start..=start + 1`
+    fn interpolate(&self, start: usize, x: NativeType) -> NativeType {
+        let (x0, x1) = (self.x(start), self.x(start + 1));
+        let (y0, y1) = (self.y(start), self.y(start + 1));
+        let width = x1 - x0;
+        let t = (x - x0) / width;
+        match self.interpolation {
+            Interpolation::Linear => y0 + (y1 - y0) * t,
+            Interpolation::Nearest => {
+                if t < 0.5 {
+                    y0
+                } else {
+                    y1
+                }
+            }
+            Interpolation::Cubic => {
+                let t2 = t * t;
+                let t3 = t2 * t;
+                let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let h10 = t3 - 2.0 * t2 + t;
+                let h01 = -2.0 * t3 + 3.0 * t2;
+                let h11 = t3 - t2;
+                h00 * y0
+                    + h10 * width * self.tangent(start)
+                    + h01 * y1
+                    + h11 * width * self.tangent(start + 1)
+            }
+        }
+    }
+
+    pub fn lookup(&self, x: X) -> Result<Y, UnitError> {
+        let x: NativeType = x.validate()?.into();
+        let last = self.points.len() - 1;
+        let (first_x, last_x) = (self.x(0), self.x(last));
+
+        if x < first_x || x > last_x {
+            let (end, segment) = if x < first_x {
+                (0, 0)
+            } else {
+                (last, last - 1)
+            };
+            return match self.extrapolation {
+                Extrapolation::Error => Err(UnitError::out_of_range::<X>(x, first_x, last_x)),
+                Extrapolation::Clamp => Ok(self.points[end].1),
+                Extrapolation::Linear => Ok(Y::from(
+                    self.y(end) + (x - self.x(end)) * self.slope(segment),
+                )),
+            };
+        }
+
+        let start = self
+            .points
+            .partition_point(|point| point.0.into() <= x)
+            .clamp(1, last)
+            - 1;
+        Ok(Y::from(self.interpolate(start, x)))
+    }
+
+    /// Finds the `X` that maps to `y`
+    ///
+    /// The table must be monotonic in `Y` (increasing or decreasing) for the
+    /// result to be unique. Linear extrapolation fails with `OutOfRange` past a
+    /// flat end segment, which never reaches `y`.
+    pub fn inverse(&self, y: Y) -> Result<X, UnitError> {
+        let y: NativeType = y.validate()?.into();
+        let last = self.points.len() - 1;
+        let (first_y, last_y) = (self.y(0), self.y(last));
+        let (minimum, maximum) = if first_y <= last_y {
+            (first_y, last_y)
+        } else {
+            (last_y, first_y)
+        };
+
+        if y < minimum || y > maximum {
+            let (end, segment) = if (y < minimum) == (first_y <= last_y) {
+                (0, 0)
+            } else {
+                (last, last - 1)
+            };
+            return match self.extrapolation {
+                Extrapolation::Error => Err(UnitError::out_of_range::<Y>(y, minimum, maximum)),
+                Extrapolation::Clamp => Ok(self.points[end].0),
+                // a flat end segment never reaches `y`
+                Extrapolation::Linear if self.slope(segment) == 0.0 => {
+                    Err(UnitError::out_of_range::<Y>(y, minimum, maximum))
+                }
+                Extrapolation::Linear => Ok(X::from(
+                    self.x(end) + (y - self.y(end)) / self.slope(segment),
+                )),
+            };
+        }
+
+        let start = (0..last)
+            .find(|&index| {
+                let (y0, y1) = (self.y(index), self.y(index + 1));
+                (y0 <= y && y <= y1) || (y1 <= y && y <= y0)
+            })
+            .unwrap_or(last - 1);
+        let (x0, x1) = (self.x(start), self.x(start + 1));
+        let (y0, y1) = (self.y(start), self.y(start + 1));
+
+        let x = match self.interpolation {
+            Interpolation::Linear => x0 + (y - y0) * (x1 - x0) / (y1 - y0),
+            Interpolation::Nearest => {
+                if (y - y0).abs() <= (y1 - y).abs() {
+                    x0
+                } else {
+                    x1
+                }
+            }
+            Interpolation::Cubic => {
+                // bisect the spline segment, keeping `y` between the ends
+                let (mut low, mut high) = (x0, x1);
+                let increasing = y1 >= y0;
+                for _ in 0..64 {
+                    let middle = (low + high) * 0.5;
+                    if (self.interpolate(start, middle) < y) == increasing {
+                        low = middle;
+                    } else {
+                        high = middle;
+                    }
+                }
+                (low + high) * 0.5
+            }
+        };
+        Ok(X::from(x))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ElectricResistance, Pressure, Temperature, VolumetricFlow};
+
+    const THERMISTOR: [(Temperature, ElectricResistance); 4] = [
+        (Temperature::new(-10.0), ElectricResistance::new(55330.0)),
+        (Temperature::new(25.0), ElectricResistance::new(10000.0)),
+        (Temperature::new(50.0), ElectricResistance::new(3603.0)),
+        (Temperature::new(100.0), ElectricResistance::new(678.0)),
+    ];
+
+    static PUMP: LookupTable<VolumetricFlow, Pressure> = LookupTable::new(
+        &[
+            (VolumetricFlow::new(0.01), Pressure::new(300_000.0)),
+            (VolumetricFlow::new(0.02), Pressure::new(250_000.0)),
+            (VolumetricFlow::new(0.03), Pressure::new(150_000.0)),
+        ],
+        Interpolation::Linear,
+        Extrapolation::Error,
+    );
+
+    #[test]
+    fn linear_lookup() {
+        assert_eq!(
+            PUMP.lookup(VolumetricFlow::new(0.015)),
+            Ok(Pressure::new(275_000.0))
+        );
+        let error = PUMP.lookup(VolumetricFlow::new(0.04)).unwrap_err();
+        assert_eq!(error.quantity(), "VolumetricFlow");
+        assert_eq!(
+            PUMP.inverse(Pressure::new(200_000.0)),
+            Ok(VolumetricFlow::new(0.025))
+        );
+
+        let table = LookupTable::new(&THERMISTOR, Interpolation::Linear, Extrapolation::Clamp);
+        assert_eq!(
+            table.lookup(Temperature::new(11.0)),
+            Ok(ElectricResistance::new(28132.0))
+        );
+        assert_eq!(
+            table.lookup(Temperature::new(150.0)),
+            Ok(ElectricResistance::new(678.0))
+        );
+        assert_eq!(
+            table.inverse(ElectricResistance::new(60000.0)),
+            Ok(Temperature::new(-10.0))
+        );
+    }
+
+    #[test]
+    fn nearest_and_extrapolated_lookup() {
+        let table = LookupTable::new(&THERMISTOR, Interpolation::Nearest, Extrapolation::Linear);
+        assert_eq!(
+            table.lookup(Temperature::new(30.0)),
+            Ok(ElectricResistance::new(10000.0))
+        );
+        assert_eq!(
+            table.inverse(ElectricResistance::new(4000.0)),
+            Ok(Temperature::new(50.0))
+        );
+        assert_eq!(
+            table.lookup(Temperature::new(110.0)),
+            Ok(ElectricResistance::new(93.0))
+        );
+    }
+
+    #[test]
+    fn cubic_lookup() {
+        let table = LookupTable::new(&THERMISTOR, Interpolation::Cubic, Extrapolation::Error);
+        for (temperature, resistance) in THERMISTOR {
+            assert_eq!(table.lookup(temperature), Ok(resistance));
+        }
+        let resistance = table.lookup(Temperature::new(40.0)).unwrap();
+        assert!(resistance < ElectricResistance::new(10000.0));
+        assert!(resistance > ElectricResistance::new(3603.0));
+        assert_eq!(table.inverse(resistance), Ok(Temperature::new(40.0)));
+    }
+
+    #[test]
+    fn cubic_is_monotone() {
+        use crate::{Length, Time};
+
+        // a step that a spline through the averaged secants overshoots
+        let points = [
+            (Time::new(0.0), Length::new(0.0)),
+            (Time::new(1.0), Length::new(0.0)),
+            (Time::new(2.0), Length::new(1.0)),
+            (Time::new(3.0), Length::new(1.0)),
+            (Time::new(4.0), Length::new(10.0)),
+        ];
+        let table = LookupTable::new(&points, Interpolation::Cubic, Extrapolation::Error);
+        let mut previous = Length::new(0.0);
+        for step in 0..=400 {
+            let length = table.lookup(Time::new(step as NativeType * 0.01)).unwrap();
+            assert!(length.native >= previous.native, "{}", step);
+            assert!(length.native <= 10.0, "{}", step);
+            previous = length;
+        }
+        let time = table.inverse(Length::new(0.5)).unwrap();
+        assert!((time.native - 1.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn unsorted_points() {
+        let points = [
+            (Temperature::new(0.0), ElectricResistance::new(1.0)),
+            (Temperature::new(10.0), ElectricResistance::new(2.0)),
+            (Temperature::new(10.0), ElectricResistance::new(3.0)),
+        ];
+        let error = LookupTable::try_new(&points, Interpolation::Linear, Extrapolation::Error)
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), UnitErrorKind::NotIncreasing);
+        assert_eq!(error.value(), 10.0);
+        assert!(
+            LookupTable::try_new(&THERMISTOR, Interpolation::Cubic, Extrapolation::Error).is_ok()
+        );
+    }
+
+    #[test]
+    fn flat_end_segment() {
+        let points = [
+            (Temperature::new(0.0), ElectricResistance::new(1.0)),
+            (Temperature::new(10.0), ElectricResistance::new(5.0)),
+            (Temperature::new(20.0), ElectricResistance::new(5.0)),
+        ];
+        let table = LookupTable::new(&points, Interpolation::Linear, Extrapolation::Linear);
+        let error = table.inverse(ElectricResistance::new(6.0)).unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::OutOfRange);
+        assert_eq!(error.limits(), Some((1.0, 5.0)));
+        assert_eq!(
+            table.inverse(ElectricResistance::new(0.5)),
+            Ok(Temperature::new(-1.25))
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use core::ops::Div;
+
+use crate::{NativeType, Quantity, Time, UnitError, UnitErrorKind};
+
+/// The state of a system of ordinary differential equations
+///
+/// This is implemented for any quantity with a time derivative (e.g. `Length`
+/// with `Velocity`) and for tuples of them. Implement it for a struct of
+/// quantities to give the state named fields.
+pub trait State: Copy {
+    /// The time derivative of each part of the state
+    type Derivative: Copy;
+
+    /// Returns `self + derivative * step`
+    fn advance(self, derivative: Self::Derivative, step: Time) -> Self;
+
+    /// Returns the largest part of `|self - other| / tolerance`
+    fn error(self, other: Self, tolerance: Self) -> NativeType;
+}
+
+impl<T, D> State for T
+where
+    T: Quantity + Div<Time, Output = D>,
+    D: Quantity,
+{
+    type Derivative = D;
+
+    fn advance(self, derivative: D, step: Time) -> Self {
+        T::from(self.into() + derivative.into() * step.native)
+    }
+
+    fn error(self, other: Self, tolerance: Self) -> NativeType {
+        let difference: NativeType = self.into() - other.into();
+        (difference / tolerance.into()).abs()
+    }
+}
+
+macro_rules! impl_state_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: State),+> State for ($($name,)+) {
+            type Derivative = ($($name::Derivative,)+);
+
+            fn advance(self, derivative: Self::Derivative, step: Time) -> Self {
+                ($(self.$index.advance(derivative.$index, step),)+)
+            }
+
+            fn error(self, other: Self, tolerance: Self) -> NativeType {
+                let mut error: NativeType = 0.0;
+                $(error = error.max(self.$index.error(other.$index, tolerance.$index));)+
+                error
+            }
+        }
+    };
+}
+
+impl_state_tuple!(A 0, B 1);
+impl_state_tuple!(A 0, B 1, C 2);
+impl_state_tuple!(A 0, B 1, C 2, D 3);
+
+/// Returns `state + step * sum(weights[i] * stages[i])`
+fn combine<S: State>(state: S, stages: &[S::Derivative], weights: &[NativeType], step: Time) -> S {
+    stages
+        .iter()
+        .zip(weights)
+        .filter(|(_, weight)| **weight != 0.0)
+        .fold(state, |state, (stage, weight)| {
+            state.advance(*stage, Time::from(step.native * weight))
+        })
+}
+
+/// Advances `state` at `time` by one classic fourth-order Runge-Kutta step
+pub fn rk4_step<S: State>(
+    derivative: &impl Fn(Time, S) -> S::Derivative,
+    time: Time,
+    state: S,
+    step: Time,
+) -> S {
+    let half = Time::from(step.native * 0.5);
+    let k1 = derivative(time, state);
+    let k2 = derivative(time + half, state.advance(k1, half));
+    let k3 = derivative(time + half, state.advance(k2, half));
+    let k4 = derivative(time + step, state.advance(k3, step));
+    combine(
+        state,
+        &[k1, k2, k3, k4],
+        &[1.0 / 6.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0],
+        step,
+    )
+}
+
+/// Integrates from `start` to `end` with fixed fourth-order Runge-Kutta steps
+///
+/// The last step is shortened to finish exactly at `end`. Fails with
+/// `NotPositive` if `step` isn't positive.
+pub fn rk4<S: State>(
+    derivative: impl Fn(Time, S) -> S::Derivative,
+    start: Time,
+    state: S,
+    end: Time,
+    step: Time,
+) -> Result<S, UnitError> {
+    positive(step)?;
+    let mut time = start;
+    let mut state = state;
+    while time < end {
+        if time + step >= end {
+            return Ok(rk4_step(&derivative, time, state, end - time));
+        }
+        state = rk4_step(&derivative, time, state, step);
+        time += step;
+    }
+    Ok(state)
+}
+
+/// Checks that a step size is greater than zero (and not NaN)
+fn positive(step: Time) -> Result<Time, UnitError> {
+    if step.native.is_nan() || step.native <= 0.0 {
+        return Err(UnitError::new::<Time>(
+            step.native,
+            UnitErrorKind::NotPositive,
+        ));
+    }
+    Ok(step)
+}
+
+// Dormand-Prince 5(4) coefficients
+const C: [NativeType; 6] = [1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A: [&[NativeType]; 6] = [
+    &[1.0 / 5.0],
+    &[3.0 / 40.0, 9.0 / 40.0],
+    &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+    &[
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+    ],
+    &[
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+    ],
+    &[
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+const FOURTH_ORDER: [NativeType; 7] = [
+    5179.0 / 57600.0,
+    0.0,
+    7571.0 / 16695.0,
+    393.0 / 640.0,
+    -92097.0 / 339200.0,
+    187.0 / 2100.0,
+    1.0 / 40.0,
+];
+
+#[cfg(feature = "f32")]
+fn step_factor(error: NativeType) -> NativeType {
+    0.9 * libm::powf(error, -0.2)
+}
+
+#[cfg(not(feature = "f32"))]
+fn step_factor(error: NativeType) -> NativeType {
+    0.9 * libm::pow(error, -0.2)
+}
+
+/// Integrates from `start` to `end` with adaptive Dormand-Prince (RK45) steps
+///
+/// `step` is the initial step size. Each step is accepted once every part of
+/// the state is within `tolerance` of the fourth-order estimate. Fails with
+/// `NotPositive` if `step` isn't positive and with `NotConverged` if the step
+/// size shrinks to nothing.
+pub fn rk45<S: State>(
+    derivative: impl Fn(Time, S) -> S::Derivative,
+    start: Time,
+    state: S,
+    end: Time,
+    step: Time,
+    tolerance: S,
+) -> Result<S, UnitError> {
+    let mut step = positive(step)?;
+    let mut time = start;
+    let mut state = state;
+
+    while time < end {
+        // `time + (end - time)` can land an ulp short of `end`
+        let last = time + step >= end;
+        if last {
+            step = end - time;
+        }
+        if step.native <= NativeType::EPSILON * time.native.abs() {
+            return Err(UnitError::new::<Time>(
+                step.native,
+                UnitErrorKind::NotConverged,
+            ));
+        }
+
+        let mut stages = [derivative(time, state); 7];
+        for (index, weights) in A.iter().enumerate() {
+            let stage_time = time + Time::from(step.native * C[index]);
+            stages[index + 1] = derivative(stage_time, combine(state, &stages, weights, step));
+        }
+        let fifth = combine(state, &stages, A[5], step);
+        let fourth = combine(state, &stages, &FOURTH_ORDER, step);
+
+        let error = fifth.error(fourth, tolerance);
+        if !error.is_finite() {
+            return Err(UnitError::new::<Time>(
+                time.native,
+                UnitErrorKind::NonFinite,
+            ));
+        }
+        if error <= 1.0 {
+            state = fifth;
+            if last {
+                return Ok(state);
+            }
+            time += step;
+        }
+        let factor = if error > 0.0 { step_factor(error) } else { 5.0 };
+        step = Time::from(step.native * factor.clamp(0.2, 5.0));
+    }
+    Ok(state)
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Acceleration, Length, Mass, PI, SurfaceTension, Velocity};
+
+    // 4 N/m spring with a 1 kg mass oscillates at 2 rad/s
+    fn spring(_: Time, (position, velocity): (Length, Velocity)) -> (Velocity, Acceleration) {
+        let force = SurfaceTension::new(-4.0) * position;
+        (velocity, force / Mass::new(1.0))
+    }
+
+    fn assert_close(state: (Length, Velocity), position: NativeType, velocity: NativeType) {
+        assert!((state.0 - Length::new(position)).abs() < Length::new(1e-4));
+        assert!((state.1 - Velocity::new(velocity)).abs() < Velocity::new(1e-4));
+    }
+
+    #[test]
+    fn fixed_step() {
+        let start = (Length::new(1.0), Velocity::new(0.0));
+        let end = Time::new(PI / 4.0);
+        let state = rk4(spring, Time::new(0.0), start, end, Time::new(0.01)).unwrap();
+        assert_close(state, 0.0, -2.0);
+
+        // exponential decay with a 2 s time constant
+        let decay = |_, velocity: Velocity| velocity / Time::new(-2.0);
+        let velocity = rk4(
+            decay,
+            Time::new(0.0),
+            Velocity::new(8.0),
+            Time::new(2.0),
+            Time::new(0.1),
+        )
+        .unwrap();
+        let expected = Velocity::new(8.0 / core::f64::consts::E as NativeType);
+        assert!((velocity - expected).abs() < Velocity::new(1e-5));
+    }
+
+    #[test]
+    fn uneven_interval() {
+        // `start + (end - start)` rounds short of `end` for these
+        #[cfg(not(feature = "f32"))]
+        let (start, end) = (Time::new(0.5649213517803819), Time::new(3.3026715817311048));
+        #[cfg(feature = "f32")]
+        let (start, end) = (Time::new(0.564_921_4), Time::new(3.302_671_7));
+        let expected = (end - start).native;
+        let constant = |_: Time, _: Length| Velocity::new(1.0);
+
+        let adaptive = rk45(
+            constant,
+            start,
+            Length::new(0.0),
+            end,
+            Time::new(10.0),
+            Length::new(1e-6),
+        )
+        .unwrap();
+        assert!((adaptive.native - expected).abs() < 1e-4);
+
+        let fixed = rk4(constant, start, Length::new(0.0), end, Time::new(0.1)).unwrap();
+        assert!((fixed.native - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn non_positive_step() {
+        let constant = |_: Time, _: Length| Velocity::new(1.0);
+        for step in [0.0, -0.1, NativeType::NAN] {
+            let error = rk45(
+                constant,
+                Time::new(0.0),
+                Length::new(0.0),
+                Time::new(1.0),
+                Time::new(step),
+                Length::new(1e-6),
+            )
+            .unwrap_err();
+            assert_eq!(error.kind(), UnitErrorKind::NotPositive);
+            let error = rk4(
+                constant,
+                Time::new(0.0),
+                Length::new(0.0),
+                Time::new(1.0),
+                Time::new(step),
+            )
+            .unwrap_err();
+            assert_eq!(error.kind(), UnitErrorKind::NotPositive);
+        }
+    }
+
+    #[test]
+    fn adaptive_step() {
+        let start = (Length::new(1.0), Velocity::new(0.0));
+        let tolerance = (Length::new(1e-6), Velocity::new(1e-6));
+        let state = rk45(
+            spring,
+            Time::new(0.0),
+            start,
+            Time::new(PI / 2.0),
+            Time::new(0.5),
+            tolerance,
+        )
+        .unwrap();
+        assert_close(state, -1.0, 0.0);
+
+        let blow_up =
+            |_, velocity: Velocity| Acceleration::new(Into::<NativeType>::into(velocity).powi(2));
+        assert!(
+            rk45(
+                blow_up,
+                Time::new(0.0),
+                Velocity::new(1.0),
+                Time::new(2.0),
+                Time::new(0.1),
+                Velocity::new(1e-6),
+            )
+            .is_err()
+        );
+    }
+}
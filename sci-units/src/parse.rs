@@ -0,0 +1,621 @@
+#[cfg(feature = "serde")]
+use core::fmt;
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use crate::imperial::{
+    Acres, Degrees, DegreesFahrenheit, DegreesPerSecond, DegreesPerSecondSquared, DegreesRankine,
+    Feet, FeetPerSecond, FeetPerSecondSquared, FootPounds, G, Gallons, Inch, InchesMercury, Knots,
+    Miles, NauticalMiles, Ounces, Pints, Pounds, PoundsForce, PoundsPerSquareFoot,
+    PoundsPerSquareInch, Quarts, Revolutions, RevolutionsPerMinute, SquareMiles, Yard,
+};
+use crate::{
+    ABSOLUTE_ZERO_CELSIUS, Acceleration, AngularAcceleration, AngularVelocity, Area, Force, Length,
+    Liters, Mass, NativeType, PlaneAngle, Prefix, Pressure, Quantity, Temperature,
+    ThermodynamicTemperature, Time, Torque, UnitErrorKind, Velocity, Volume,
+};
+
+/// The part of the text that couldn't be parsed
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParseErrorKind {
+    /// There is no text other than whitespace
+    Empty,
+    /// The number is missing or isn't a valid float
+    Number,
+    /// There is no unit after the number
+    MissingUnit,
+    /// The unit is known but the prefix in front of it isn't (e.g. `"5 kft"`)
+    Prefix,
+    /// The unit isn't one that converts into the quantity
+    Unit,
+    /// The value parsed but isn't valid for the quantity (e.g. `"-5 K"`)
+    Value(UnitErrorKind),
+}
+
+impl ParseErrorKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ParseErrorKind::Empty => "is empty",
+            ParseErrorKind::Number => "has an invalid number",
+            ParseErrorKind::MissingUnit => "is missing a unit",
+            ParseErrorKind::Prefix => "has an invalid unit prefix",
+            ParseErrorKind::Unit => "has an unknown unit",
+            ParseErrorKind::Value(kind) => kind.description(),
+        }
+    }
+}
+
+/// Error returned when parsing a quantity from a string
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    quantity: &'static str,
+    kind: ParseErrorKind,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    fn new<T: Quantity>(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        Self {
+            quantity: T::NAME,
+            kind,
+            span,
+        }
+    }
+
+    /// Name of the quantity type being parsed (e.g. `"Length"`)
+    pub fn quantity(&self) -> &'static str {
+        self.quantity
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Byte range of the part of the text that failed to parse
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}: text {} at {}..{}",
+            self.quantity,
+            self.kind.description(),
+            self.span.start,
+            self.span.end
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+#[cfg(feature = "use_defmt")]
+impl defmt::Format for ParseError {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "{}: text {} at {}..{}",
+            self.quantity,
+            self.kind.description(),
+            self.span.start,
+            self.span.end
+        );
+    }
+}
+
+/// A unit that can be written after the number when parsing a quantity
+struct Unit {
+    /// `Quantity::NAME` of the type the unit converts into
+    quantity: &'static str,
+    symbol: &'static str,
+    ascii: &'static str,
+    singular: &'static str,
+    plural: &'static str,
+    label: &'static str,
+    /// Old labels and other spellings of the names
+    aliases: &'static [&'static str],
+    prefixable: bool,
+    /// Converts a value in this unit into the natives of `quantity`
+    convert: fn(NativeType) -> NativeType,
+}
+
+impl Unit {
+    fn of<T: Quantity>() -> Self {
+        Self {
+            quantity: T::NAME,
+            symbol: T::SYMBOL,
+            ascii: T::ASCII_SYMBOL,
+            singular: T::SINGULAR,
+            plural: T::PLURAL,
+            label: T::LABEL,
+            aliases: T::ALIASES,
+            prefixable: T::PREFIXABLE,
+            convert: |value| value,
+        }
+    }
+
+    fn symbols(&self) -> impl Iterator<Item = &'static str> {
+        [self.symbol, self.ascii]
+            .into_iter()
+            .filter(|symbol| !symbol.is_empty())
+    }
+
+    fn is_symbol(&self, text: &str) -> bool {
+        self.symbols().any(|symbol| text == symbol)
+    }
+
+    /// The long names, label and aliases, which are matched ignoring ASCII case
+    fn names(&self) -> impl Iterator<Item = &'static str> {
+        [self.singular, self.plural, self.label]
+            .into_iter()
+            .chain(self.aliases.iter().copied())
+            .filter(|name| !name.is_empty())
+    }
+
+    fn is_name(&self, text: &str) -> bool {
+        self.names().any(|name| name.eq_ignore_ascii_case(text))
+    }
+
+    /// The text in front of the symbol or name at the end of `text`
+    fn leading<'a>(&self, text: &'a str) -> Option<&'a str> {
+        if let Some(leading) = self.symbols().find_map(|symbol| text.strip_suffix(symbol)) {
+            return Some(leading);
+        }
+        [self.singular, self.plural]
+            .into_iter()
+            .chain(self.aliases.iter().copied())
+            .filter(|name| !name.is_empty() && name.len() < text.len())
+            .find_map(|name| {
+                let split = text.len() - name.len();
+                match (text.get(..split), text.get(split..)) {
+                    (Some(leading), Some(end)) if end.eq_ignore_ascii_case(name) => Some(leading),
+                    _ => None,
+                }
+            })
+    }
+
+    /// The prefix multiplier if `text` is a prefixed symbol or name of this unit
+    fn prefixed(&self, text: &str) -> Option<NativeType> {
+        if !self.prefixable {
+            return None;
+        }
+        if let Some(prefix) = self
+            .symbols()
+            .find_map(|symbol| text.strip_suffix(symbol).and_then(Prefix::from_symbol))
+        {
+            return Some(prefix.factor());
+        }
+        let leading = self.leading(text)?;
+        Prefix::from_name(leading).map(|prefix| prefix.factor())
+    }
+}
+
+/// Writes a row of `UNITS` that parses the units of `$from` into `$into`
+macro_rules! convert {
+    ($from:ty => $into:ty) => {
+        Unit {
+            quantity: <$into as Quantity>::NAME,
+            symbol: <$from as Quantity>::SYMBOL,
+            ascii: <$from as Quantity>::ASCII_SYMBOL,
+            singular: <$from as Quantity>::SINGULAR,
+            plural: <$from as Quantity>::PLURAL,
+            label: <$from as Quantity>::LABEL,
+            aliases: <$from as Quantity>::ALIASES,
+            prefixable: <$from as Quantity>::PREFIXABLE,
+            convert: |value| {
+                let converted: $into = <$from>::new(value).into();
+                converted.into()
+            },
+        }
+    };
+}
+
+/// Units other than its own that each quantity accepts when parsing
+const UNITS: &[Unit] = &[
+    convert!(Feet => Length),
+    convert!(Inch => Length),
+    convert!(Yard => Length),
+    convert!(Miles => Length),
+    convert!(NauticalMiles => Length),
+    convert!(Acres => Area),
+    convert!(SquareMiles => Area),
+    convert!(Liters => Volume),
+    convert!(Pints => Volume),
+    convert!(Quarts => Volume),
+    convert!(Gallons => Volume),
+    Unit {
+        quantity: <Mass as Quantity>::NAME,
+        symbol: "g",
+        ascii: "g",
+        singular: "gram",
+        plural: "grams",
+        label: "grams",
+        aliases: &[],
+        prefixable: true,
+        convert: |value| value * 1e-3,
+    },
+    convert!(Pounds => Mass),
+    convert!(Ounces => Mass),
+    Unit {
+        quantity: <Time as Quantity>::NAME,
+        symbol: "min",
+        ascii: "min",
+        singular: "minute",
+        plural: "minutes",
+        label: "minutes",
+        aliases: &[],
+        prefixable: false,
+        convert: |value| value * 60.0,
+    },
+    Unit {
+        quantity: <Time as Quantity>::NAME,
+        symbol: "h",
+        ascii: "h",
+        singular: "hour",
+        plural: "hours",
+        label: "hours",
+        aliases: &[],
+        prefixable: false,
+        convert: |value| value * 3_600.0,
+    },
+    Unit {
+        quantity: <Time as Quantity>::NAME,
+        symbol: "d",
+        ascii: "d",
+        singular: "day",
+        plural: "days",
+        label: "days",
+        aliases: &[],
+        prefixable: false,
+        convert: |value| value * 86_400.0,
+    },
+    convert!(Degrees => PlaneAngle),
+    convert!(Revolutions => PlaneAngle),
+    convert!(DegreesPerSecond => AngularVelocity),
+    convert!(RevolutionsPerMinute => AngularVelocity),
+    convert!(DegreesPerSecondSquared => AngularAcceleration),
+    convert!(Knots => Velocity),
+    convert!(FeetPerSecond => Velocity),
+    Unit {
+        quantity: <Velocity as Quantity>::NAME,
+        symbol: "km/h",
+        ascii: "km/h",
+        singular: "kilometer/hour",
+        plural: "kilometers/hour",
+        label: "kilometers/hour",
+        aliases: &[],
+        prefixable: false,
+        convert: |value| value / 3.6,
+    },
+    convert!(G => Acceleration),
+    convert!(FeetPerSecondSquared => Acceleration),
+    convert!(PoundsForce => Force),
+    convert!(PoundsPerSquareInch => Pressure),
+    convert!(PoundsPerSquareFoot => Pressure),
+    convert!(InchesMercury => Pressure),
+    Unit {
+        quantity: <Pressure as Quantity>::NAME,
+        symbol: "bar",
+        ascii: "bar",
+        singular: "bar",
+        plural: "bars",
+        label: "bars",
+        aliases: &[],
+        prefixable: true,
+        convert: |value| value * 1e5,
+    },
+    convert!(FootPounds => Torque),
+    convert!(DegreesFahrenheit => Temperature),
+    Unit {
+        quantity: <Temperature as Quantity>::NAME,
+        symbol: <ThermodynamicTemperature as Quantity>::SYMBOL,
+        ascii: <ThermodynamicTemperature as Quantity>::ASCII_SYMBOL,
+        singular: <ThermodynamicTemperature as Quantity>::SINGULAR,
+        plural: <ThermodynamicTemperature as Quantity>::PLURAL,
+        label: <ThermodynamicTemperature as Quantity>::LABEL,
+        aliases: <ThermodynamicTemperature as Quantity>::ALIASES,
+        prefixable: false,
+        convert: |value| value + ABSOLUTE_ZERO_CELSIUS,
+    },
+    convert!(DegreesRankine => ThermodynamicTemperature),
+    Unit {
+        quantity: <ThermodynamicTemperature as Quantity>::NAME,
+        symbol: <Temperature as Quantity>::SYMBOL,
+        ascii: <Temperature as Quantity>::ASCII_SYMBOL,
+        singular: <Temperature as Quantity>::SINGULAR,
+        plural: <Temperature as Quantity>::PLURAL,
+        label: <Temperature as Quantity>::LABEL,
+        aliases: <Temperature as Quantity>::ALIASES,
+        prefixable: false,
+        convert: |value| value - ABSOLUTE_ZERO_CELSIUS,
+    },
+];
+
+/// Lists the units `T` accepts, for error messages (e.g. `` `m`, `meter`, `meters`, `ft`, ... ``)
+#[cfg(feature = "serde")]
+pub(crate) struct Accepted<T>(PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<T> Accepted<T> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Quantity> fmt::Display for Accepted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let own = Unit::of::<T>();
+        let units = core::iter::once(&own).chain(UNITS.iter().filter(|u| u.quantity == T::NAME));
+        let mut separator = "";
+        let mut prefixable = None;
+        for unit in units {
+            let names = || unit.symbols().chain(unit.names());
+            for (index, name) in names().enumerate() {
+                if names().take(index).any(|earlier| earlier == name) {
+                    continue;
+                }
+                write!(f, "{}`{}`", separator, name)?;
+                separator = ", ";
+            }
+            if unit.prefixable && prefixable.is_none() {
+                prefixable = Some(unit.symbol);
+            }
+        }
+        match prefixable {
+            Some(symbol) => write!(f, " with an optional SI prefix (e.g. `k{}`)", symbol),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Length of the float at the start of `text` (sign, digits, point and exponent)
+fn number_length(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let digits = |mut index: usize| {
+        while index < bytes.len() && (bytes[index].is_ascii_digit() || bytes[index] == b'.') {
+            index += 1;
+        }
+        index
+    };
+    let sign = |index: usize| match bytes.get(index) {
+        Some(b'+' | b'-') => index + 1,
+        _ => index,
+    };
+
+    let end = digits(sign(0));
+    // only an `e` followed by digits is an exponent (`"3 EJ"` is exajoules)
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let exponent = sign(end + 1);
+        if bytes.get(exponent).is_some_and(u8::is_ascii_digit) {
+            return digits(exponent);
+        }
+    }
+    end
+}
+
+/// Converts a number written in some unit into the natives of a quantity
+#[derive(Copy, Clone)]
+pub(crate) struct Conversion {
+    factor: NativeType,
+    convert: fn(NativeType) -> NativeType,
+}
+
+impl Conversion {
+    pub(crate) fn apply(&self, number: NativeType) -> NativeType {
+        (self.convert)(number * self.factor)
+    }
+}
+
+/// Finds how to convert a number followed by `unit` into `T`, or what is
+/// wrong with the unit and where in it
+pub(crate) fn conversion<T: Quantity>(
+    unit: &str,
+) -> Result<Conversion, (ParseErrorKind, Range<usize>)> {
+    let own = Unit::of::<T>();
+    if unit.is_empty() {
+        if !T::SYMBOL.is_empty() {
+            return Err((ParseErrorKind::MissingUnit, 0..0));
+        }
+        return Ok(Conversion {
+            factor: 1.0,
+            convert: own.convert,
+        });
+    }
+
+    let units = || core::iter::once(&own).chain(UNITS.iter().filter(|u| u.quantity == T::NAME));
+    let found = units()
+        .find(|candidate| candidate.is_symbol(unit))
+        .or_else(|| units().find(|candidate| candidate.is_name(unit)))
+        .map(|candidate| (1.0, candidate))
+        .or_else(|| {
+            units().find_map(|candidate| candidate.prefixed(unit).map(|factor| (factor, candidate)))
+        });
+    match found {
+        Some((factor, candidate)) => Ok(Conversion {
+            factor,
+            convert: candidate.convert,
+        }),
+        // a known unit with something unexpected in front of it
+        None => Err(
+            match units().find_map(|candidate| candidate.leading(unit)) {
+                Some(leading) => (ParseErrorKind::Prefix, 0..leading.len()),
+                None => (ParseErrorKind::Unit, 0..unit.len()),
+            },
+        ),
+    }
+}
+
+/// Parses text like `"3.2 km"`, `"12mV"`, `"4.7 kΩ"` or `"5 ft"` into `T`
+///
+/// The unit can be a symbol (unicode or ASCII, e.g. `"kOhm"`) or a long name (singular or plural) with an
+/// optional SI prefix, or a unit that converts into `T` (e.g. feet into
+/// `Length`). Long names ignore ASCII case, symbols don't.
+pub fn parse<T: Quantity>(text: &str) -> Result<T, ParseError> {
+    let value = parse_unvalidated::<T>(text)?;
+    value.validate().map_err(|error| {
+        let start = text.len() - text.trim_start().len();
+        ParseError::new::<T>(
+            ParseErrorKind::Value(error.kind()),
+            start..text.trim_end().len(),
+        )
+    })
+}
+
+/// Like `parse` but without checking the value is valid for `T`
+pub(crate) fn parse_unvalidated<T: Quantity>(text: &str) -> Result<T, ParseError> {
+    let start = text.len() - text.trim_start().len();
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new::<T>(ParseErrorKind::Empty, 0..text.len()));
+    }
+
+    let length = number_length(trimmed);
+    let number: NativeType = trimmed[..length].parse().map_err(|_| {
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        ParseError::new::<T>(ParseErrorKind::Number, start..start + end.max(length))
+    })?;
+
+    let unit = trimmed[length..].trim_start();
+    let unit_start = start + trimmed.len() - unit.len();
+
+    match conversion::<T>(unit) {
+        Ok(conversion) => Ok(T::from(conversion.apply(number))),
+        Err((kind, span)) => Err(ParseError::new::<T>(
+            kind,
+            unit_start + span.start..unit_start + span.end,
+        )),
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Capacitance, ElectricCurrent, ElectricPotential, ElectricResistance, Scalar};
+
+    #[test]
+    fn aliases() {
+        assert_eq!(
+            "20 celcius".parse::<Temperature>().unwrap(),
+            Temperature::new(20.0)
+        );
+        assert_eq!("2 Metres".parse::<Length>().unwrap(), Length::new(2.0));
+        assert_eq!(
+            "3 kilometres".parse::<Length>().unwrap(),
+            Length::new(3_000.0)
+        );
+        assert_eq!(
+            "1.5 amperes".parse::<ElectricCurrent>().unwrap(),
+            ElectricCurrent::new(1.5)
+        );
+        assert_eq!(
+            Accepted::<Temperature>::new().to_string(),
+            "`°C`, `degC`, `degree Celsius`, `degrees Celsius`, `celsius`, `celcius`, \
+             `°F`, `degF`, `degree Fahrenheit`, `degrees Fahrenheit`, `degreesF`, `fahrenheit`, \
+             `K`, `kelvin`"
+        );
+        assert!(
+            Accepted::<Length>::new()
+                .to_string()
+                .ends_with("`nauticalmiles` with an optional SI prefix (e.g. `km`)")
+        );
+    }
+
+    #[test]
+    fn parse_symbols_and_names() {
+        assert_eq!("3.2 km".parse::<Length>().unwrap(), Length::new(3_200.0));
+        assert_eq!(
+            "12mV".parse::<ElectricPotential>().unwrap(),
+            ElectricPotential::new(0.012)
+        );
+        assert_eq!(
+            "4.7 kΩ".parse::<ElectricResistance>().unwrap(),
+            ElectricResistance::new(4_700.0)
+        );
+        assert_eq!(
+            "4.7 kiloohms".parse::<ElectricResistance>().unwrap(),
+            ElectricResistance::new(4_700.0)
+        );
+        assert_eq!(
+            "1.2e3 Pa".parse::<Pressure>().unwrap(),
+            Pressure::new(1_200.0)
+        );
+        assert_eq!(
+            " 100 uF ".parse::<Capacitance>().unwrap(),
+            Capacitance::new(1e-4)
+        );
+        assert_eq!("-1 Meter".parse::<Length>().unwrap(), Length::new(-1.0));
+        assert_eq!(
+            "2 volts".parse::<ElectricPotential>().unwrap(),
+            ElectricPotential::new(2.0)
+        );
+        assert_eq!("0.5".parse::<Scalar>().unwrap(), Scalar::new(0.5));
+        assert_eq!("1e-3 mm".parse::<Length>().unwrap(), Length::new(1e-6));
+        assert_eq!(
+            "4.7 kOhm".parse::<ElectricResistance>().unwrap(),
+            ElectricResistance::new(4_700.0)
+        );
+        assert_eq!(
+            "9.81 m/s^2".parse::<Acceleration>().unwrap(),
+            Acceleration::new(9.81)
+        );
+    }
+
+    #[test]
+    fn parse_conversions() {
+        assert!(("5 ft".parse::<Length>().unwrap() - Length::new(1.524)).abs() < Length::new(1e-6));
+        assert_eq!("5 ft".parse::<Feet>().unwrap(), Feet::new(5.0));
+        assert_eq!("250 mg".parse::<Mass>().unwrap(), Mass::new(2.5e-4));
+        assert_eq!("2 hours".parse::<Time>().unwrap(), Time::new(7_200.0));
+        assert_eq!("500 mL".parse::<Volume>().unwrap(), Volume::new(5e-4));
+        assert!(
+            ("212 °F".parse::<Temperature>().unwrap() - Temperature::new(100.0)).abs()
+                < Temperature::new(1e-3)
+        );
+        assert_eq!(
+            "0 °C".parse::<ThermodynamicTemperature>().unwrap(),
+            ThermodynamicTemperature::new(273.15)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = "  ".parse::<Length>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Empty);
+
+        let error = "abc m".parse::<Length>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Number);
+        assert_eq!(error.span(), 0..3);
+
+        let error = "3.2".parse::<Length>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::MissingUnit);
+
+        let error = "5 kft".parse::<Length>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Prefix);
+        assert_eq!(error.span(), 2..3);
+
+        let error = "3 xm".parse::<Length>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Prefix);
+
+        let error = "3 V".parse::<Length>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Unit);
+        assert_eq!(error.span(), 2..3);
+        assert_eq!(error.quantity(), "Length");
+        assert_eq!(
+            error.to_string(),
+            "Length: text has an unknown unit at 2..3"
+        );
+
+        let error = "-5 K".parse::<ThermodynamicTemperature>().unwrap_err();
+        assert_eq!(
+            error.kind(),
+            ParseErrorKind::Value(UnitErrorKind::BelowAbsoluteZero)
+        );
+    }
+}
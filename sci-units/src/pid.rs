@@ -0,0 +1,268 @@
+use core::marker::PhantomData;
+
+use crate::{NativeType, Quantity, Time, UnitError, UnitErrorKind};
+
+/// Proportional gain with units of output / error
+pub struct ProportionalGain<O, E> {
+    native: NativeType,
+    units: PhantomData<(O, E)>,
+}
+
+impl<O: Quantity, E: Quantity> ProportionalGain<O, E> {
+    /// A gain that produces `output` for an error of `error`
+    pub fn new(output: O, error: E) -> Self {
+        Self {
+            native: output.into() / error.into(),
+            units: PhantomData,
+        }
+    }
+
+    pub fn apply(&self, error: E) -> O {
+        O::from(self.native * error.into())
+    }
+}
+
+/// Integral gain with units of output / (error * time)
+pub struct IntegralGain<O, E> {
+    native: NativeType,
+    units: PhantomData<(O, E)>,
+}
+
+impl<O: Quantity, E: Quantity> IntegralGain<O, E> {
+    /// A gain that produces `output` after an error of `error` persists for `time`
+    pub fn new(output: O, error: E, time: Time) -> Self {
+        Self {
+            native: output.into() / (error.into() * time.native),
+            units: PhantomData,
+        }
+    }
+
+    pub fn apply(&self, error: E, period: Time) -> O {
+        O::from(self.native * error.into() * period.native)
+    }
+}
+
+/// Derivative gain with units of output * time / error
+pub struct DerivativeGain<O, E> {
+    native: NativeType,
+    units: PhantomData<(O, E)>,
+}
+
+impl<O: Quantity, E: Quantity> DerivativeGain<O, E> {
+    /// A gain that produces `output` when the error changes by `error` over `time`
+    pub fn new(output: O, time: Time, error: E) -> Self {
+        Self {
+            native: output.into() * time.native / error.into(),
+            units: PhantomData,
+        }
+    }
+
+    pub fn apply(&self, change: E, period: Time) -> O {
+        O::from(self.native * change.into() / period.native)
+    }
+}
+
+macro_rules! impl_gain_traits {
+    ($gain:ident) => {
+        impl<O, E> Copy for $gain<O, E> {}
+
+        impl<O, E> Clone for $gain<O, E> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<O, E> $gain<O, E> {
+            pub fn zero() -> Self {
+                Self {
+                    native: 0.0,
+                    units: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+impl_gain_traits!(ProportionalGain);
+impl_gain_traits!(IntegralGain);
+impl_gain_traits!(DerivativeGain);
+
+/// PID controller from a measured quantity `E` to an output quantity `O`
+///
+/// The derivative acts on the measurement rather than the error so that
+/// setpoint changes don't cause output spikes. The integral is held while the
+/// output is saturated to prevent windup.
+pub struct Pid<E, O> {
+    kp: ProportionalGain<O, E>,
+    ki: IntegralGain<O, E>,
+    kd: DerivativeGain<O, E>,
+    integral: NativeType,
+    derivative: Option<NativeType>,
+    previous_measurement: Option<E>,
+    derivative_time_constant: Option<Time>,
+    output_limits: Option<(O, O)>,
+}
+
+impl<E: Quantity, O: Quantity> Pid<E, O> {
+    pub fn new(
+        kp: ProportionalGain<O, E>,
+        ki: IntegralGain<O, E>,
+        kd: DerivativeGain<O, E>,
+    ) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            integral: 0.0,
+            derivative: None,
+            previous_measurement: None,
+            derivative_time_constant: None,
+            output_limits: None,
+        }
+    }
+
+    /// Clamps the output (and the integral term) to `minimum..=maximum`
+    ///
+    /// Either limit may be infinite. Fails with `NonFinite` if a limit is NaN
+    /// or `OutOfRange` if `minimum` is greater than `maximum`, leaving the
+    /// previous limits in place.
+    pub fn set_output_limits(&mut self, minimum: O, maximum: O) -> Result<(), UnitError> {
+        let (low, high): (NativeType, NativeType) = (minimum.into(), maximum.into());
+        if let Some(nan) = [low, high].into_iter().find(|limit| limit.is_nan()) {
+            return Err(UnitError::new::<O>(nan, UnitErrorKind::NonFinite));
+        }
+        if low > high {
+            return Err(UnitError::out_of_range::<O>(
+                low,
+                NativeType::NEG_INFINITY,
+                high,
+            ));
+        }
+        self.output_limits = Some((minimum, maximum));
+        self.integral = self.clamp(self.integral);
+        Ok(())
+    }
+
+    /// Low-pass filters the derivative term with a first-order filter
+    pub fn set_derivative_filter(&mut self, time_constant: Time) {
+        self.derivative_time_constant = Some(time_constant);
+    }
+
+    /// The accumulated integral term
+    pub fn integral(&self) -> O {
+        O::from(self.integral)
+    }
+
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.derivative = None;
+        self.previous_measurement = None;
+    }
+
+    fn clamp(&self, native: NativeType) -> NativeType {
+        match self.output_limits {
+            Some((minimum, maximum)) => native.clamp(minimum.into(), maximum.into()),
+            None => native,
+        }
+    }
+
+    /// Calculates the output given the time since the previous update
+    pub fn update(&mut self, setpoint: E, measurement: E, period: Time) -> O {
+        let error = E::from(setpoint.into() - measurement.into());
+        let proportional: NativeType = self.kp.apply(error).into();
+
+        let derivative = match self.previous_measurement {
+            Some(previous) => {
+                let change = E::from(previous.into() - measurement.into());
+                let raw: NativeType = self.kd.apply(change, period).into();
+                match (self.derivative, self.derivative_time_constant) {
+                    (Some(state), Some(time_constant)) => {
+                        let alpha = period.native / (time_constant.native + period.native);
+                        state + alpha * (raw - state)
+                    }
+                    _ => raw,
+                }
+            }
+            None => 0.0,
+        };
+        self.derivative = Some(derivative);
+        self.previous_measurement = Some(measurement);
+
+        let step: NativeType = self.ki.apply(error, period).into();
+        let integral = self.clamp(self.integral + step);
+        let output = proportional + integral + derivative;
+        let limited = self.clamp(output);
+
+        // only integrate when it doesn't push further into saturation
+        if limited == output || (output > limited) != (step > 0.0) {
+            self.integral = integral;
+        }
+
+        O::from(self.clamp(proportional + self.integral + derivative))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Power, Temperature};
+
+    // heater in a box that loses 2 W/°C to a 20 °C room and heats at 0.1 °C/J
+    fn simulate(pid: &mut Pid<Temperature, Power>, setpoint: Temperature) -> Temperature {
+        let period = Time::new(0.1);
+        let mut temperature = Temperature::new(20.0);
+        for _ in 0..20_000 {
+            let power: NativeType = pid.update(setpoint, temperature, period).into();
+            let loss = 2.0 * (Into::<NativeType>::into(temperature) - 20.0);
+            temperature += Temperature::new(0.1 * (power - loss) * period.native);
+        }
+        temperature
+    }
+
+    #[test]
+    fn pid_reaches_setpoint() {
+        let mut pid = Pid::new(
+            ProportionalGain::new(Power::new(10.0), Temperature::new(1.0)),
+            IntegralGain::new(Power::new(1.0), Temperature::new(1.0), Time::new(1.0)),
+            DerivativeGain::new(Power::new(1.0), Time::new(1.0), Temperature::new(1.0)),
+        );
+        pid.set_derivative_filter(Time::new(0.5));
+        let temperature = simulate(&mut pid, Temperature::new(60.0));
+        assert!((temperature - Temperature::new(60.0)).abs() < Temperature::new(0.01));
+        assert!((pid.integral() - Power::new(80.0)).abs() < Power::new(0.01));
+    }
+
+    #[test]
+    fn pid_output_limits() {
+        let mut pid = Pid::new(
+            ProportionalGain::new(Power::new(10.0), Temperature::new(1.0)),
+            IntegralGain::new(Power::new(5.0), Temperature::new(1.0), Time::new(1.0)),
+            DerivativeGain::zero(),
+        );
+        pid.set_output_limits(Power::new(-50.0), Power::new(50.0))
+            .unwrap();
+
+        // invalid limits are rejected rather than panicking in `update`
+        let error = pid
+            .set_output_limits(Power::new(50.0), Power::new(-50.0))
+            .unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::OutOfRange);
+        let error = pid
+            .set_output_limits(Power::new(NativeType::NAN), Power::new(50.0))
+            .unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::NonFinite);
+
+        // 50 W can only hold 45 °C, so the output saturates
+        let temperature = simulate(&mut pid, Temperature::new(60.0));
+        assert!((temperature - Temperature::new(45.0)).abs() < Temperature::new(0.01));
+        assert!(pid.integral() <= Power::new(50.0));
+
+        // without windup the controller reacts to a lower setpoint immediately
+        let output = pid.update(Temperature::new(40.0), temperature, Time::new(0.1));
+        assert!(output < Power::new(50.0));
+
+        pid.reset();
+        assert_eq!(pid.integral(), Power::new(0.0));
+    }
+}
@@ -0,0 +1,554 @@
+use core::marker::PhantomData;
+use core::ops::{Div, Mul};
+
+use bincode::{Decode, Encode};
+
+use crate::solver::{Tolerance, brent};
+use crate::{NativeType, Quantity, UnitError, UnitErrorKind};
+
+/// Calibration polynomial `y = c0 + c1 x + c2 x^2 + ...` with `N` coefficients
+///
+/// Coefficient `i` is in the units of `Y / X^i` (e.g. volts per count squared
+/// for `c2` of an ADC calibration from `Scalar` to `ElectricPotential`).
+#[derive(Copy, Clone, Debug, PartialEq, Encode, Decode)]
+#[bincode(bounds = "")]
+pub struct Polynomial<X, Y, const N: usize> {
+    coefficients: [NativeType; N],
+    units: PhantomData<(X, Y)>,
+}
+
+impl<X: Quantity, Y: Quantity, const N: usize> Polynomial<X, Y, N> {
+    /// Coefficients in order of increasing power, each in SI units of `Y / X^i`
+    ///
+    /// The coefficients aren't type checked; `linear`, `quadratic`, `cubic`,
+    /// `quartic` and `quintic` build first to fifth order polynomials from
+    /// typed terms.
+    pub const fn new(coefficients: [NativeType; N]) -> Self {
+        assert!(N > 0, "a polynomial needs at least one coefficient");
+        Self {
+            coefficients,
+            units: PhantomData,
+        }
+    }
+
+    pub fn coefficients(&self) -> &[NativeType; N] {
+        &self.coefficients
+    }
+
+    /// The constant term `c0`
+    pub fn offset(&self) -> Y {
+        Y::from(self.coefficients[0])
+    }
+
+    /// The linear term `c1`, which is zero for a constant polynomial
+    pub fn linear_term<S>(&self) -> S
+    where
+        Y: Div<X, Output = S>,
+        S: Quantity,
+    {
+        S::from(self.coefficients.get(1).copied().unwrap_or(0.0))
+    }
+
+    /// The quadratic term `c2`, which is zero below second order
+    pub fn quadratic_term<S, C>(&self) -> C
+    where
+        Y: Div<X, Output = S>,
+        S: Div<X, Output = C>,
+        C: Quantity,
+    {
+        C::from(self.coefficients.get(2).copied().unwrap_or(0.0))
+    }
+
+    /// The cubic term `c3`, which is zero below third order
+    pub fn cubic_term<S, C, K>(&self) -> K
+    where
+        Y: Div<X, Output = S>,
+        S: Div<X, Output = C>,
+        C: Div<X, Output = K>,
+        K: Quantity,
+    {
+        K::from(self.coefficients.get(3).copied().unwrap_or(0.0))
+    }
+
+    /// The quartic term `c4`, which is zero below fourth order
+    pub fn quartic_term<S, C, K, Q>(&self) -> Q
+    where
+        Y: Div<X, Output = S>,
+        S: Div<X, Output = C>,
+        C: Div<X, Output = K>,
+        K: Div<X, Output = Q>,
+        Q: Quantity,
+    {
+        Q::from(self.coefficients.get(4).copied().unwrap_or(0.0))
+    }
+
+    /// The quintic term `c5`, which is zero below fifth order
+    pub fn quintic_term<S, C, K, Q, P>(&self) -> P
+    where
+        Y: Div<X, Output = S>,
+        S: Div<X, Output = C>,
+        C: Div<X, Output = K>,
+        K: Div<X, Output = Q>,
+        Q: Div<X, Output = P>,
+        P: Quantity,
+    {
+        P::from(self.coefficients.get(5).copied().unwrap_or(0.0))
+    }
+
+    /// The order of the polynomial (one less than the number of coefficients)
+    pub const fn order(&self) -> usize {
+        N - 1
+    }
+
+    /// Evaluates the polynomial using Horner's method
+    pub fn evaluate(&self, x: X) -> Y {
+        let x: NativeType = x.into();
+        Y::from(
+            self.coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |sum, coefficient| sum * x + coefficient),
+        )
+    }
+
+    /// The slope `dy/dx` at `x`
+    pub fn slope<D>(&self, x: X) -> D
+    where
+        Y: Div<X, Output = D>,
+        D: Quantity,
+    {
+        let x: NativeType = x.into();
+        D::from(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .rev()
+                .fold(0.0, |sum, (power, coefficient)| {
+                    sum * x + power as NativeType * coefficient
+                }),
+        )
+    }
+
+    /// Least-squares fit to `(x, y)` reference points
+    ///
+    /// Fails with `Singular` if there are fewer than `N` distinct `x` values.
+    pub fn fit(points: &[(X, Y)]) -> Result<Self, UnitError> {
+        // scale x to about 1 so that high powers don't lose precision
+        let scale = points
+            .iter()
+            .map(|point| Into::<NativeType>::into(point.0).abs())
+            .fold(0.0, NativeType::max);
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+
+        // normal equations: (A^T A) c = A^T y
+        let mut matrix = [[0.0; N]; N];
+        let mut vector = [0.0; N];
+        for (x, y) in points {
+            let x: NativeType = Into::<NativeType>::into(*x) / scale;
+            let y: NativeType = (*y).into();
+            let mut row = [1.0; N];
+            for power in 1..N {
+                row[power] = row[power - 1] * x;
+            }
+            for i in 0..N {
+                vector[i] += row[i] * y;
+                for j in 0..N {
+                    matrix[i][j] += row[i] * row[j];
+                }
+            }
+        }
+
+        // a pivot this small relative to the matrix means the points don't
+        // determine every coefficient, whatever the units' magnitudes
+        let norm = matrix
+            .iter()
+            .map(|row| row.iter().map(|value| value.abs()).sum::<NativeType>())
+            .fold(0.0, NativeType::max);
+        let threshold = norm * NativeType::EPSILON * N as NativeType;
+
+        // gaussian elimination with partial pivoting
+        for column in 0..N {
+            let pivot = (column..N)
+                .max_by(|a, b| {
+                    matrix[*a][column]
+                        .abs()
+                        .total_cmp(&matrix[*b][column].abs())
+                })
+                .unwrap_or(column);
+            matrix.swap(column, pivot);
+            vector.swap(column, pivot);
+            if matrix[column][column].abs() <= threshold {
+                return Err(UnitError::new_with_label(
+                    "Polynomial",
+                    "points",
+                    points.len() as NativeType,
+                    UnitErrorKind::Singular,
+                ));
+            }
+            let pivot_row = matrix[column];
+            for row in column + 1..N {
+                let factor = matrix[row][column] / pivot_row[column];
+                for (value, pivot) in matrix[row].iter_mut().zip(pivot_row).skip(column) {
+                    *value -= factor * pivot;
+                }
+                vector[row] -= factor * vector[column];
+            }
+        }
+
+        let mut coefficients = [0.0; N];
+        for row in (0..N).rev() {
+            let sum: NativeType = (row + 1..N)
+                .map(|index| matrix[row][index] * coefficients[index])
+                .sum();
+            coefficients[row] = (vector[row] - sum) / matrix[row][row];
+        }
+
+        let mut power = 1.0;
+        for coefficient in coefficients.iter_mut() {
+            *coefficient /= power;
+            power *= scale;
+        }
+        Ok(Self::new(coefficients))
+    }
+
+    /// Finds the `x` between `lower` and `upper` that gives `y`
+    ///
+    /// The polynomial must be monotonic over the range. Fails with
+    /// `NotBracketed` if `y` is outside of the range.
+    pub fn inverse(&self, y: Y, lower: X, upper: X) -> Result<X, UnitError> {
+        let target: NativeType = y.into();
+        let range: NativeType = upper.into() - lower.into();
+        let tolerance = Tolerance::new(
+            X::from((range * NativeType::EPSILON * 16.0).abs()),
+            Y::from(0.0),
+            100,
+        );
+        let solution = brent(
+            |x| Y::from(Into::<NativeType>::into(self.evaluate(x)) - target),
+            lower,
+            upper,
+            tolerance,
+        )?;
+        if solution.converged() {
+            Ok(solution.x())
+        } else {
+            Err(UnitError::new::<X>(
+                solution.x().into(),
+                UnitErrorKind::NotConverged,
+            ))
+        }
+    }
+}
+
+impl<X, Y> Polynomial<X, Y, 2>
+where
+    X: Quantity,
+    Y: Quantity,
+{
+    /// Straight line calibration from an offset and a typed slope
+    pub fn linear<S>(offset: Y, slope: S) -> Self
+    where
+        S: Quantity + Mul<X, Output = Y>,
+    {
+        Self::new([offset.into(), slope.into()])
+    }
+}
+
+impl<X, Y> Polynomial<X, Y, 3>
+where
+    X: Quantity,
+    Y: Quantity,
+{
+    /// Second order calibration from typed terms (e.g. volts, volts per count
+    /// and volts per count squared)
+    pub fn quadratic<S, C>(offset: Y, linear: S, quadratic: C) -> Self
+    where
+        S: Quantity + Mul<X, Output = Y>,
+        C: Quantity + Mul<X, Output = S>,
+    {
+        Self::new([offset.into(), linear.into(), quadratic.into()])
+    }
+}
+
+impl<X, Y> Polynomial<X, Y, 4>
+where
+    X: Quantity,
+    Y: Quantity,
+{
+    /// Third order polynomial from typed terms (e.g. metres, metres per second,
+    /// per second squared and per second cubed)
+    pub fn cubic<S, C, K>(offset: Y, linear: S, quadratic: C, cubic: K) -> Self
+    where
+        S: Quantity + Mul<X, Output = Y>,
+        C: Quantity + Mul<X, Output = S>,
+        K: Quantity + Mul<X, Output = C>,
+    {
+        Self::new([offset.into(), linear.into(), quadratic.into(), cubic.into()])
+    }
+}
+
+impl<X, Y> Polynomial<X, Y, 5>
+where
+    X: Quantity,
+    Y: Quantity,
+{
+    /// Fourth order polynomial from typed terms
+    pub fn quartic<S, C, K, Q>(offset: Y, linear: S, quadratic: C, cubic: K, quartic: Q) -> Self
+    where
+        S: Quantity + Mul<X, Output = Y>,
+        C: Quantity + Mul<X, Output = S>,
+        K: Quantity + Mul<X, Output = C>,
+        Q: Quantity + Mul<X, Output = K>,
+    {
+        Self::new([
+            offset.into(),
+            linear.into(),
+            quadratic.into(),
+            cubic.into(),
+            quartic.into(),
+        ])
+    }
+}
+
+impl<X, Y> Polynomial<X, Y, 6>
+where
+    X: Quantity,
+    Y: Quantity,
+{
+    /// Fifth order polynomial from typed terms
+    pub fn quintic<S, C, K, Q, P>(
+        offset: Y,
+        linear: S,
+        quadratic: C,
+        cubic: K,
+        quartic: Q,
+        quintic: P,
+    ) -> Self
+    where
+        S: Quantity + Mul<X, Output = Y>,
+        C: Quantity + Mul<X, Output = S>,
+        K: Quantity + Mul<X, Output = C>,
+        Q: Quantity + Mul<X, Output = K>,
+        P: Quantity + Mul<X, Output = Q>,
+    {
+        Self::new([
+            offset.into(),
+            linear.into(),
+            quadratic.into(),
+            cubic.into(),
+            quartic.into(),
+            quintic.into(),
+        ])
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<X, Y, const N: usize> serde::Serialize for Polynomial<X, Y, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for coefficient in &self.coefficients {
+            tuple.serialize_element(coefficient)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, X, Y, const N: usize> serde::Deserialize<'de> for Polynomial<X, Y, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CoefficientsVisitor<const N: usize>;
+
+        impl<'de, const N: usize> serde::de::Visitor<'de> for CoefficientsVisitor<N> {
+            type Value = [NativeType; N];
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(formatter, "{} polynomial coefficients", N)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut coefficients = [0.0; N];
+                for (index, coefficient) in coefficients.iter_mut().enumerate() {
+                    *coefficient = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                Ok(coefficients)
+            }
+        }
+
+        Ok(Self {
+            coefficients: deserializer.deserialize_tuple(N, CoefficientsVisitor::<N>)?,
+            units: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Acceleration, ElectricPotential, Jerk, Length, Scalar, Temperature, Time, Velocity,
+    };
+
+    // 12-bit ADC with a slightly non-linear front end
+    fn adc() -> Polynomial<Scalar, ElectricPotential, 3> {
+        Polynomial::new([0.05, 0.001, 2e-8])
+    }
+
+    #[test]
+    fn evaluate_polynomial() {
+        let calibration = adc();
+        assert_eq!(calibration.order(), 2);
+        assert_eq!(
+            calibration.evaluate(Scalar::new(1000.0)),
+            ElectricPotential::new(1.07)
+        );
+        let slope: ElectricPotential = calibration.slope(Scalar::new(1000.0));
+        assert_eq!(slope, ElectricPotential::new(0.00104));
+
+        assert_eq!(calibration.offset(), ElectricPotential::new(0.05));
+        let gain: ElectricPotential = calibration.linear_term();
+        assert_eq!(gain, ElectricPotential::new(0.001));
+        let curvature: ElectricPotential = calibration.quadratic_term();
+        assert_eq!(curvature, ElectricPotential::new(2e-8));
+        let typed = Polynomial::<Scalar, ElectricPotential, 3>::quadratic(
+            ElectricPotential::new(0.05),
+            ElectricPotential::new(0.001),
+            ElectricPotential::new(2e-8),
+        );
+        assert_eq!(typed, calibration);
+
+        let fall = Polynomial::<Time, Length, 3>::quadratic(
+            Length::new(100.0),
+            Velocity::new(2.0),
+            Acceleration::new(-4.9),
+        );
+        assert_eq!(fall.evaluate(Time::new(2.0)), Length::new(84.4));
+        let initial: Velocity = fall.linear_term();
+        assert_eq!(initial, Velocity::new(2.0));
+        let half_g: Acceleration = fall.quadratic_term();
+        assert_eq!(half_g, Acceleration::new(-4.9));
+
+        let path = Polynomial::<Time, Length, 4>::cubic(
+            Length::new(1.0),
+            Velocity::new(2.0),
+            Acceleration::new(3.0),
+            Jerk::new(4.0),
+        );
+        assert_eq!(path.evaluate(Time::new(2.0)), Length::new(49.0));
+        let jerk: Jerk = path.cubic_term();
+        assert_eq!(jerk, Jerk::new(4.0));
+
+        let sensor = Polynomial::<Scalar, ElectricPotential, 6>::quintic(
+            ElectricPotential::new(1.0),
+            ElectricPotential::new(2.0),
+            ElectricPotential::new(3.0),
+            ElectricPotential::new(4.0),
+            ElectricPotential::new(5.0),
+            ElectricPotential::new(6.0),
+        );
+        assert_eq!(
+            sensor.evaluate(Scalar::new(1.0)),
+            ElectricPotential::new(21.0)
+        );
+        let quartic: ElectricPotential = sensor.quartic_term();
+        assert_eq!(quartic, ElectricPotential::new(5.0));
+        let quintic: ElectricPotential = sensor.quintic_term();
+        assert_eq!(quintic, ElectricPotential::new(6.0));
+        let quartic = Polynomial::<Scalar, ElectricPotential, 5>::quartic(
+            ElectricPotential::new(1.0),
+            ElectricPotential::new(2.0),
+            ElectricPotential::new(3.0),
+            ElectricPotential::new(4.0),
+            ElectricPotential::new(5.0),
+        );
+        assert_eq!(quartic.coefficients(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let line = Polynomial::<Scalar, ElectricPotential, 2>::linear(
+            ElectricPotential::new(0.0),
+            ElectricPotential::new(0.004),
+        );
+        assert_eq!(
+            line.evaluate(Scalar::new(500.0)),
+            ElectricPotential::new(2.0)
+        );
+    }
+
+    #[test]
+    fn fit_and_invert() {
+        let reference = adc();
+        let points: std::vec::Vec<(Scalar, ElectricPotential)> =
+            [0.0, 800.0, 1600.0, 2400.0, 4095.0]
+                .into_iter()
+                .map(|count| (Scalar::new(count), reference.evaluate(Scalar::new(count))))
+                .collect();
+        let fitted = Polynomial::<Scalar, ElectricPotential, 3>::fit(&points).unwrap();
+        for (actual, expected) in fitted.coefficients().iter().zip(reference.coefficients()) {
+            assert!(((actual - expected) / expected).abs() < 1e-4);
+        }
+
+        let count = fitted
+            .inverse(
+                ElectricPotential::new(1.07),
+                Scalar::new(0.0),
+                Scalar::new(4095.0),
+            )
+            .unwrap();
+        assert!((count - Scalar::new(1000.0)).abs() < Scalar::new(0.01));
+        let error = fitted
+            .inverse(
+                ElectricPotential::new(9.0),
+                Scalar::new(0.0),
+                Scalar::new(4095.0),
+            )
+            .unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::NotBracketed);
+
+        let too_few = [(Temperature::new(1.0), Scalar::new(2.0))];
+        let error = Polynomial::<Temperature, Scalar, 2>::fit(&too_few).unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::Singular);
+        assert_eq!(
+            error.to_string(),
+            "Polynomial: 1 points don't determine a unique solution"
+        );
+        // the singular check doesn't depend on the magnitude of the units
+        let tiny: std::vec::Vec<(Time, Length)> = [1.0, 2.0, 3.0]
+            .into_iter()
+            .map(|t| (Time::new(t * 1e-9), Length::new(t * t * 1e-12)))
+            .collect();
+        let fitted = Polynomial::<Time, Length, 3>::fit(&tiny).unwrap();
+        let curvature: Acceleration = fitted.quadratic_term();
+        assert!((curvature.native / 1e6 - 1.0).abs() < 1e-3);
+
+        let repeated = [(Temperature::new(1.0), Scalar::new(2.0)); 3];
+        let error = Polynomial::<Temperature, Scalar, 2>::fit(&repeated).unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::Singular);
+    }
+
+    #[test]
+    fn store_polynomial() {
+        let calibration = adc();
+        let json = serde_json::to_string(&calibration).unwrap();
+        let restored: Polynomial<Scalar, ElectricPotential, 3> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, calibration);
+
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(calibration, config).unwrap();
+        let (decoded, _): (Polynomial<Scalar, ElectricPotential, 3>, usize) =
+            bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, calibration);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::NativeType;
+
+/// A metric (SI) prefix such as kilo or micro
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Prefix {
+    Quecto,
+    Ronto,
+    Yocto,
+    Zepto,
+    Atto,
+    Femto,
+    Pico,
+    Nano,
+    Micro,
+    Milli,
+    Centi,
+    Deci,
+    Deca,
+    Hecto,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+    Peta,
+    Exa,
+    Zetta,
+    Yotta,
+    Ronna,
+    Quetta,
+}
+
+impl Prefix {
+    /// Every prefix from smallest to largest
+    pub const ALL: [Prefix; 24] = [
+        Prefix::Quecto,
+        Prefix::Ronto,
+        Prefix::Yocto,
+        Prefix::Zepto,
+        Prefix::Atto,
+        Prefix::Femto,
+        Prefix::Pico,
+        Prefix::Nano,
+        Prefix::Micro,
+        Prefix::Milli,
+        Prefix::Centi,
+        Prefix::Deci,
+        Prefix::Deca,
+        Prefix::Hecto,
+        Prefix::Kilo,
+        Prefix::Mega,
+        Prefix::Giga,
+        Prefix::Tera,
+        Prefix::Peta,
+        Prefix::Exa,
+        Prefix::Zetta,
+        Prefix::Yotta,
+        Prefix::Ronna,
+        Prefix::Quetta,
+    ];
+
+    /// The symbol put in front of a unit symbol (e.g. `"k"` in `"kV"`)
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Prefix::Quecto => "q",
+            Prefix::Ronto => "r",
+            Prefix::Yocto => "y",
+            Prefix::Zepto => "z",
+            Prefix::Atto => "a",
+            Prefix::Femto => "f",
+            Prefix::Pico => "p",
+            Prefix::Nano => "n",
+            Prefix::Micro => "µ",
+            Prefix::Milli => "m",
+            Prefix::Centi => "c",
+            Prefix::Deci => "d",
+            Prefix::Deca => "da",
+            Prefix::Hecto => "h",
+            Prefix::Kilo => "k",
+            Prefix::Mega => "M",
+            Prefix::Giga => "G",
+            Prefix::Tera => "T",
+            Prefix::Peta => "P",
+            Prefix::Exa => "E",
+            Prefix::Zetta => "Z",
+            Prefix::Yotta => "Y",
+            Prefix::Ronna => "R",
+            Prefix::Quetta => "Q",
+        }
+    }
+
+    /// The symbol without unicode characters (`"u"` for micro)
+    pub fn ascii_symbol(&self) -> &'static str {
+        match self {
+            Prefix::Micro => "u",
+            _ => self.symbol(),
+        }
+    }
+
+    /// The name put in front of a unit name (e.g. `"kilo"` in `"kilovolts"`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Prefix::Quecto => "quecto",
+            Prefix::Ronto => "ronto",
+            Prefix::Yocto => "yocto",
+            Prefix::Zepto => "zepto",
+            Prefix::Atto => "atto",
+            Prefix::Femto => "femto",
+            Prefix::Pico => "pico",
+            Prefix::Nano => "nano",
+            Prefix::Micro => "micro",
+            Prefix::Milli => "milli",
+            Prefix::Centi => "centi",
+            Prefix::Deci => "deci",
+            Prefix::Deca => "deca",
+            Prefix::Hecto => "hecto",
+            Prefix::Kilo => "kilo",
+            Prefix::Mega => "mega",
+            Prefix::Giga => "giga",
+            Prefix::Tera => "tera",
+            Prefix::Peta => "peta",
+            Prefix::Exa => "exa",
+            Prefix::Zetta => "zetta",
+            Prefix::Yotta => "yotta",
+            Prefix::Ronna => "ronna",
+            Prefix::Quetta => "quetta",
+        }
+    }
+
+    /// The power of ten the prefix stands for
+    pub fn exponent(&self) -> i32 {
+        match self {
+            Prefix::Quecto => -30,
+            Prefix::Ronto => -27,
+            Prefix::Yocto => -24,
+            Prefix::Zepto => -21,
+            Prefix::Atto => -18,
+            Prefix::Femto => -15,
+            Prefix::Pico => -12,
+            Prefix::Nano => -9,
+            Prefix::Micro => -6,
+            Prefix::Milli => -3,
+            Prefix::Centi => -2,
+            Prefix::Deci => -1,
+            Prefix::Deca => 1,
+            Prefix::Hecto => 2,
+            Prefix::Kilo => 3,
+            Prefix::Mega => 6,
+            Prefix::Giga => 9,
+            Prefix::Tera => 12,
+            Prefix::Peta => 15,
+            Prefix::Exa => 18,
+            Prefix::Zetta => 21,
+            Prefix::Yotta => 24,
+            Prefix::Ronna => 27,
+            Prefix::Quetta => 30,
+        }
+    }
+
+    /// The multiplier the prefix stands for (e.g. `1e3` for kilo)
+    pub fn factor(&self) -> NativeType {
+        match self {
+            Prefix::Quecto => 1e-30,
+            Prefix::Ronto => 1e-27,
+            Prefix::Yocto => 1e-24,
+            Prefix::Zepto => 1e-21,
+            Prefix::Atto => 1e-18,
+            Prefix::Femto => 1e-15,
+            Prefix::Pico => 1e-12,
+            Prefix::Nano => 1e-9,
+            Prefix::Micro => 1e-6,
+            Prefix::Milli => 1e-3,
+            Prefix::Centi => 1e-2,
+            Prefix::Deci => 1e-1,
+            Prefix::Deca => 1e1,
+            Prefix::Hecto => 1e2,
+            Prefix::Kilo => 1e3,
+            Prefix::Mega => 1e6,
+            Prefix::Giga => 1e9,
+            Prefix::Tera => 1e12,
+            Prefix::Peta => 1e15,
+            Prefix::Exa => 1e18,
+            Prefix::Zetta => 1e21,
+            Prefix::Yotta => 1e24,
+            Prefix::Ronna => 1e27,
+            Prefix::Quetta => 1e30,
+        }
+    }
+
+    /// Looks up a prefix by its power of ten
+    pub fn from_exponent(exponent: i32) -> Option<Prefix> {
+        Prefix::ALL
+            .iter()
+            .find(|prefix| prefix.exponent() == exponent)
+            .copied()
+    }
+
+    /// Looks up a prefix by its symbol
+    ///
+    /// Micro can be written with the micro sign, the Greek mu or `u`.
+    pub fn from_symbol(symbol: &str) -> Option<Prefix> {
+        match symbol {
+            "μ" | "u" => Some(Prefix::Micro),
+            _ => Prefix::ALL
+                .iter()
+                .find(|prefix| prefix.symbol() == symbol)
+                .copied(),
+        }
+    }
+
+    /// Looks up a prefix by its name, ignoring ASCII case
+    pub fn from_name(name: &str) -> Option<Prefix> {
+        Prefix::ALL
+            .iter()
+            .find(|prefix| prefix.name().eq_ignore_ascii_case(name))
+            .copied()
+    }
+}
@@ -0,0 +1,313 @@
+use core::fmt::{self, Write};
+
+use crate::Prefix;
+
+/// One symbol of a unit raised to a power (e.g. `kg⁻¹` in `J kg⁻¹ K⁻¹`)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Factor {
+    pub symbol: &'static str,
+    pub power: i8,
+}
+
+/// The markup language a quantity is rendered in
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Markup {
+    /// Plain text (e.g. `"3.2 km"`)
+    #[default]
+    Text,
+    /// siunitx LaTeX (e.g. `\qty{3.2}{\kilo\meter}`)
+    Latex,
+    /// HTML with superscript powers (e.g. `3.2&nbsp;J·kg<sup>-1</sup>`)
+    Html,
+    /// A MathML `<math>` element
+    MathMl,
+}
+
+/// The siunitx macro for a unit symbol
+fn latex_macro(symbol: &str) -> Option<&'static str> {
+    Some(match symbol {
+        "m" => r"\meter",
+        "kg" => r"\kilogram",
+        "g" => r"\gram",
+        "s" => r"\second",
+        "A" => r"\ampere",
+        "K" => r"\kelvin",
+        "mol" => r"\mole",
+        "cd" => r"\candela",
+        "rad" => r"\radian",
+        "sr" => r"\steradian",
+        "Hz" => r"\hertz",
+        "N" => r"\newton",
+        "Pa" => r"\pascal",
+        "J" => r"\joule",
+        "W" => r"\watt",
+        "C" => r"\coulomb",
+        "V" => r"\volt",
+        "F" => r"\farad",
+        "Ω" => r"\ohm",
+        "S" => r"\siemens",
+        "Wb" => r"\weber",
+        "T" => r"\tesla",
+        "H" => r"\henry",
+        "°C" => r"\degreeCelsius",
+        "lm" => r"\lumen",
+        "lx" => r"\lux",
+        "L" => r"\liter",
+        "dB" => r"\decibel",
+        "Np" => r"\neper",
+        "B" => r"\bel",
+        "°" => r"\degree",
+        _ => return None,
+    })
+}
+
+/// The symbol in siunitx literal input, where it has no macro
+fn latex_literal(symbol: &str) -> &str {
+    match symbol {
+        "°F" => r"{}^{\circ}F",
+        "°R" => r"{}^{\circ}R",
+        _ => symbol,
+    }
+}
+
+/// Writes the number, turning the exponent of scientific notation into markup
+pub(crate) struct MarkupNumber<'a, W> {
+    output: &'a mut W,
+    markup: Markup,
+    exponent: bool,
+}
+
+impl<'a, W: Write> MarkupNumber<'a, W> {
+    pub(crate) fn new(output: &'a mut W, markup: Markup) -> Result<Self, fmt::Error> {
+        if markup == Markup::MathMl {
+            output.write_str("<mn>")?;
+        }
+        Ok(Self {
+            output,
+            markup,
+            exponent: false,
+        })
+    }
+
+    pub(crate) fn finish(self) -> fmt::Result {
+        match (self.markup, self.exponent) {
+            (Markup::Html, true) => self.output.write_str("</sup>"),
+            (Markup::MathMl, true) => self.output.write_str("</mn></msup>"),
+            (Markup::MathMl, false) => self.output.write_str("</mn>"),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Write for MarkupNumber<'_, W> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        for character in text.chars() {
+            match (self.markup, character) {
+                (Markup::Html, 'e') => {
+                    self.exponent = true;
+                    self.output.write_str("×10<sup>")?;
+                }
+                (Markup::MathMl, 'e') => {
+                    self.exponent = true;
+                    self.output
+                        .write_str("</mn><mo>×</mo><msup><mn>10</mn><mn>")?;
+                }
+                _ => self.output.write_char(character)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the opening markup before the number
+pub(crate) fn open(f: &mut impl Write, markup: Markup, unit: &[Factor]) -> fmt::Result {
+    match markup {
+        Markup::Text => Ok(()),
+        Markup::Latex if unit.is_empty() => f.write_str(r"\num{"),
+        Markup::Latex => f.write_str(r"\qty{"),
+        Markup::Html => Ok(()),
+        Markup::MathMl => f.write_str("<math><mrow>"),
+    }
+}
+
+/// Writes the unit (with `prefix` on its first symbol) and the closing markup
+pub(crate) fn close(
+    f: &mut impl Write,
+    markup: Markup,
+    prefix: Option<Prefix>,
+    unit: &[Factor],
+) -> fmt::Result {
+    match markup {
+        Markup::Text => Ok(()),
+        Markup::Latex => {
+            if unit.is_empty() {
+                return f.write_str("}");
+            }
+            f.write_str("}{")?;
+            if unit
+                .iter()
+                .all(|factor| latex_macro(factor.symbol).is_some())
+            {
+                latex_macros(f, prefix, unit)?;
+            } else {
+                latex_literals(f, prefix, unit)?;
+            }
+            f.write_str("}")
+        }
+        Markup::Html => {
+            for (index, factor) in unit.iter().enumerate() {
+                f.write_str(if index == 0 { "&nbsp;" } else { "·" })?;
+                if let (0, Some(prefix)) = (index, prefix) {
+                    f.write_str(prefix.symbol())?;
+                }
+                f.write_str(factor.symbol)?;
+                if factor.power != 1 {
+                    write!(f, "<sup>{}</sup>", factor.power)?;
+                }
+            }
+            Ok(())
+        }
+        Markup::MathMl => {
+            if !unit.is_empty() {
+                f.write_str("<mspace width=\"0.2em\"/>")?;
+            }
+            for (index, factor) in unit.iter().enumerate() {
+                if index > 0 {
+                    f.write_str("<mo>·</mo>")?;
+                }
+                if factor.power != 1 {
+                    f.write_str("<msup>")?;
+                }
+                f.write_str("<mi mathvariant=\"normal\">")?;
+                if let (0, Some(prefix)) = (index, prefix) {
+                    f.write_str(prefix.symbol())?;
+                }
+                f.write_str(factor.symbol)?;
+                f.write_str("</mi>")?;
+                if factor.power != 1 {
+                    write!(f, "<mn>{}</mn></msup>", factor.power)?;
+                }
+            }
+            f.write_str("</mrow></math>")
+        }
+    }
+}
+
+/// `\kilo\joule\per\kilogram\per\kelvin`
+fn latex_macros(f: &mut impl Write, prefix: Option<Prefix>, unit: &[Factor]) -> fmt::Result {
+    for (index, factor) in unit.iter().enumerate() {
+        if factor.power < 0 {
+            f.write_str(r"\per")?;
+        }
+        match factor.power.unsigned_abs() {
+            1 => {}
+            2 => f.write_str(r"\square")?,
+            3 => f.write_str(r"\cubic")?,
+            power => write!(f, r"\raiseto{{{}}}", power)?,
+        }
+        if let (0, Some(prefix)) = (index, prefix) {
+            write!(f, r"\{}", prefix.name())?;
+        }
+        f.write_str(latex_macro(factor.symbol).unwrap_or(factor.symbol))?;
+    }
+    Ok(())
+}
+
+/// `ft.lbf` or `psi^{-1}`
+fn latex_literals(f: &mut impl Write, prefix: Option<Prefix>, unit: &[Factor]) -> fmt::Result {
+    for (index, factor) in unit.iter().enumerate() {
+        if index > 0 {
+            f.write_str(".")?;
+        }
+        if let (0, Some(prefix)) = (index, prefix) {
+            f.write_str(prefix.ascii_symbol())?;
+        }
+        f.write_str(latex_literal(factor.symbol))?;
+        if factor.power != 1 {
+            write!(f, "^{{{}}}", factor.power)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use crate::imperial::{DegreesFahrenheit, FootPounds};
+    use crate::{
+        Length, MassDensity, Quantity, Scalar, SpecificHeatCapacity, Temperature, VelocitySquared,
+    };
+
+    use super::Factor;
+
+    #[test]
+    fn unit_structure() {
+        let factor = |symbol, power| Factor { symbol, power };
+        assert_eq!(Length::UNIT, &[factor("m", 1)]);
+        assert_eq!(
+            SpecificHeatCapacity::UNIT,
+            &[factor("J", 1), factor("kg", -1), factor("K", -1)]
+        );
+        assert_eq!(VelocitySquared::UNIT, &[factor("m", 2), factor("s", -2)]);
+        assert_eq!(MassDensity::UNIT, &[factor("kg", 1), factor("m", -3)]);
+        assert_eq!(Scalar::UNIT, &[]);
+    }
+
+    #[test]
+    fn latex() {
+        assert_eq!(
+            Length::new(3_200.0).engineering().latex().to_string(),
+            r"\qty{3.2}{\kilo\meter}"
+        );
+        assert_eq!(
+            SpecificHeatCapacity::new(4_186.0)
+                .format()
+                .latex()
+                .to_string(),
+            r"\qty{4186}{\joule\per\kilogram\per\kelvin}"
+        );
+        assert_eq!(
+            format!("{:.1}", Temperature::new(21.5).format().latex()),
+            r"\qty{21.5}{\degreeCelsius}"
+        );
+        assert_eq!(
+            Length::new(2.5e-5).format().latex().to_string(),
+            r"\qty{2.5e-5}{\meter}"
+        );
+        assert_eq!(
+            FootPounds::new(3.0).format().latex().to_string(),
+            r"\qty{3}{ft.lbf}"
+        );
+        assert_eq!(
+            DegreesFahrenheit::new(70.0).format().latex().to_string(),
+            r"\qty{70}{{}^{\circ}F}"
+        );
+        assert_eq!(Scalar::new(0.5).format().latex().to_string(), r"\num{0.5}");
+    }
+
+    #[test]
+    fn html_and_mathml() {
+        assert_eq!(
+            SpecificHeatCapacity::new(4_186.0)
+                .format()
+                .html()
+                .to_string(),
+            "4186&nbsp;J·kg<sup>-1</sup>·K<sup>-1</sup>"
+        );
+        assert_eq!(
+            Length::new(2.5e-5).format().html().to_string(),
+            "2.5×10<sup>-5</sup>&nbsp;m"
+        );
+        assert_eq!(
+            Length::new(3_200.0).engineering().mathml().to_string(),
+            "<math><mrow><mn>3.2</mn><mspace width=\"0.2em\"/>\
+             <mi mathvariant=\"normal\">km</mi></mrow></math>"
+        );
+        assert_eq!(
+            MassDensity::new(2.5e-5).format().mathml().to_string(),
+            "<math><mrow><mn>2.5</mn><mo>×</mo><msup><mn>10</mn><mn>-5</mn></msup>\
+             <mspace width=\"0.2em\"/><mi mathvariant=\"normal\">kg</mi><mo>·</mo>\
+             <msup><mi mathvariant=\"normal\">m</mi><mn>-3</mn></msup></mrow></math>"
+        );
+    }
+}
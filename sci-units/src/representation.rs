@@ -0,0 +1,325 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::parse::{self, Accepted, Conversion, ParseErrorKind};
+use crate::{NativeType, Quantity};
+
+// Values aren't validated when they're loaded, so that anything `new` can
+// build (e.g. `Mass::new(-1.0)`) round-trips through every representation.
+
+/// `10.0`: the value in SI units
+///
+/// Use with `#[serde(with = "sci_units::representation::number")]`. Human-readable
+/// formats also accept the string and object forms.
+pub mod number {
+    use super::*;
+
+    pub fn serialize<T: Quantity, S: Serializer>(
+        quantity: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let native: NativeType = (*quantity).into();
+        native.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Quantity, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(QuantityVisitor::<T>(PhantomData))
+        } else {
+            NativeType::deserialize(deserializer).map(T::from)
+        }
+    }
+}
+
+/// `{ "value": 10.0, "unit": "m" }`: the value with the unit symbol
+///
+/// Use with `#[serde(with = "sci_units::representation::object")]`. The unit can be
+/// any unit that converts into the quantity (e.g. `{ "value": 5, "unit": "ft" }`
+/// for a `Length`).
+pub mod object {
+    use super::*;
+
+    pub fn serialize<T: Quantity, S: Serializer>(
+        quantity: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let native: NativeType = (*quantity).into();
+        let mut object = serializer.serialize_struct("Quantity", 2)?;
+        object.serialize_field("value", &native)?;
+        object.serialize_field("unit", T::SYMBOL)?;
+        object.end()
+    }
+
+    pub fn deserialize<'de, T: Quantity, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_struct("Quantity", FIELDS, QuantityVisitor::<T>(PhantomData))
+    }
+}
+
+/// `"10_meters"`: the value and the unit label, which is what `Serialize` writes
+///
+/// Deserialising also accepts anything `str::parse` does (e.g. `"5 ft"` or
+/// `"4.7 kΩ"`) and, in human-readable formats, the number and object forms.
+pub mod string {
+    use super::*;
+
+    pub fn serialize<T: Quantity, S: Serializer>(
+        quantity: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let native: NativeType = (*quantity).into();
+        serializer.collect_str(&format_args!("{}_{}", native, T::LABEL))
+    }
+
+    pub fn deserialize<'de, T: Quantity, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(QuantityVisitor::<T>(PhantomData))
+        } else {
+            deserializer.deserialize_str(QuantityVisitor::<T>(PhantomData))
+        }
+    }
+}
+
+const FIELDS: &[&str] = &["value", "unit"];
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Value,
+    Unit,
+}
+
+/// An error for text whose unit isn't accepted, listing the units that are
+fn unit_error<T: Quantity, E: de::Error>(text: &str, kind: ParseErrorKind) -> E {
+    E::custom(format_args!(
+        "{}: `{}` {}; expected one of {}",
+        T::NAME,
+        text,
+        kind.description(),
+        Accepted::<T>::new()
+    ))
+}
+
+/// Reads any of the representations of `T`
+struct QuantityVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Quantity> Visitor<'de> for QuantityVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a {} like `10.0_{}`, `\"10 {}\"`, `10.0` or `{{ \"value\": 10.0, \"unit\": \"{}\" }}`",
+            T::NAME,
+            T::LABEL,
+            T::SYMBOL,
+            T::SYMBOL
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<T, E> {
+        // `"10_meters"` as written by `Serialize`, or with any accepted unit after the `_`
+        if let Some((value, unit)) = text.rsplit_once('_')
+            && let Ok(native) = value.trim().parse::<NativeType>()
+        {
+            return parse::conversion::<T>(unit.trim())
+                .map(|conversion| T::from(conversion.apply(native)))
+                .map_err(|(kind, _)| unit_error::<T, E>(unit, kind));
+        }
+        parse::parse_unvalidated(text).map_err(|error| match error.kind() {
+            kind
+            @ (ParseErrorKind::MissingUnit | ParseErrorKind::Prefix | ParseErrorKind::Unit) => {
+                unit_error::<T, E>(text, kind)
+            }
+            _ => E::custom(error),
+        })
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<T, E> {
+        Ok(T::from(value as NativeType))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        Ok(T::from(value as NativeType))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        Ok(T::from(value as NativeType))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        let mut value: Option<NativeType> = None;
+        let mut conversion: Option<Conversion> = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Value if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                Field::Unit if conversion.is_some() => {
+                    return Err(de::Error::duplicate_field("unit"));
+                }
+                Field::Value => value = Some(map.next_value()?),
+                Field::Unit => conversion = Some(map.next_value_seed(UnitSeed::<T>(PhantomData))?),
+            }
+        }
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let conversion = conversion.ok_or_else(|| de::Error::missing_field("unit"))?;
+        Ok(T::from(conversion.apply(value)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let value: NativeType = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let conversion = seq
+            .next_element_seed(UnitSeed::<T>(PhantomData))?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(T::from(conversion.apply(value)))
+    }
+}
+
+/// Looks up the unit of an object as it is read, so it needn't be stored
+struct UnitSeed<T>(PhantomData<T>);
+
+impl<'de, T: Quantity> DeserializeSeed<'de> for UnitSeed<T> {
+    type Value = Conversion;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Conversion, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, T: Quantity> Visitor<'de> for UnitSeed<T> {
+    type Value = Conversion;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a unit of {} like `\"{}\"`", T::NAME, T::SYMBOL)
+    }
+
+    fn visit_str<E: de::Error>(self, unit: &str) -> Result<Conversion, E> {
+        parse::conversion::<T>(unit.trim()).map_err(|(kind, _)| unit_error::<T, E>(unit, kind))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use crate::{Capacitance, Length, Temperature};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Reading {
+        #[serde(with = "crate::representation::number")]
+        number: Length,
+        #[serde(with = "crate::representation::object")]
+        object: Length,
+        #[serde(with = "crate::representation::string")]
+        string: Length,
+    }
+
+    #[test]
+    fn representations() {
+        let length = Length::new(10.0);
+        let reading = Reading {
+            number: length,
+            object: length,
+            string: length,
+        };
+        let json = serde_json::to_string(&reading).unwrap();
+        assert_eq!(
+            json,
+            r#"{"number":10.0,"object":{"value":10.0,"unit":"m"},"string":"10_meters"}"#
+        );
+        assert_eq!(serde_json::from_str::<Reading>(&json).unwrap(), reading);
+    }
+
+    #[test]
+    fn convertible_units() {
+        let json = r#"{"number":"5 ft","object":{"unit":"ft","value":5},"string":"1.524 m"}"#;
+        let reading: Reading = serde_json::from_str(json).unwrap();
+        assert!((reading.number.native - 1.524).abs() < 1e-6);
+        assert!((reading.object.native - 1.524).abs() < 1e-6);
+        assert!((reading.string.native - 1.524).abs() < 1e-6);
+
+        let capacitance: Capacitance = serde_json::from_str(r#""4.7 µF""#).unwrap();
+        assert!((capacitance.native - 4.7e-6).abs() < 1e-12);
+        let temperature: Temperature =
+            serde_json::from_str(r#"{"value":212,"unit":"°F"}"#).unwrap();
+        assert!((temperature.native - 100.0).abs() < 1e-3);
+        // numbers are in SI units
+        assert_eq!(
+            serde_json::from_str::<Length>("3").unwrap(),
+            Length::new(3.0)
+        );
+    }
+
+    #[test]
+    fn invalid_units() {
+        let error = serde_json::from_str::<Length>(r#"{"value":5,"unit":"kg"}"#).unwrap_err();
+        assert!(error.to_string().starts_with(
+            "Length: `kg` has an unknown unit; expected one of `m`, `meter`, `meters`, `metre`"
+        ));
+        let error = serde_json::from_str::<Length>(r#"{"value":5}"#).unwrap_err();
+        assert!(error.to_string().contains("missing field `unit`"));
+        let error = serde_json::from_str::<Length>(r#""5 kfeet""#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("Length: `5 kfeet` has an invalid unit prefix;")
+        );
+        assert!(serde_json::from_str::<Length>(r#""5 kg""#).is_err());
+    }
+
+    #[test]
+    fn invalid_values_round_trip() {
+        use crate::Mass;
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Weights {
+            #[serde(with = "crate::representation::number")]
+            number: Mass,
+            #[serde(with = "crate::representation::object")]
+            object: Mass,
+            #[serde(with = "crate::representation::string")]
+            string: Mass,
+        }
+
+        let mass = Mass::new(-1.0);
+        let json = serde_json::to_string(&Weights {
+            number: mass,
+            object: mass,
+            string: mass,
+        })
+        .unwrap();
+        let weights: Weights = serde_json::from_str(&json).unwrap();
+        assert_eq!(weights.number, mass);
+        assert_eq!(weights.object, mass);
+        assert_eq!(weights.string, mass);
+        assert_eq!(serde_json::from_str::<Mass>(r#""-1 kg""#).unwrap(), mass);
+    }
+
+    #[test]
+    fn tolerant_labels() {
+        for text in [
+            r#""10_meters""#,
+            r#""10_Meters""#,
+            r#""10_m""#,
+            r#""10_metres""#,
+            r#""0.01_km""#,
+            r#""10 meters""#,
+        ] {
+            let length: Length = serde_json::from_str(text).unwrap();
+            assert!((length.native - 10.0).abs() < 1e-9, "{}", text);
+        }
+        let temperature: Temperature = serde_json::from_str(r#""20_celcius""#).unwrap();
+        assert_eq!(temperature, Temperature::new(20.0));
+        let error = serde_json::from_str::<Temperature>(r#""20_centigrade""#).unwrap_err();
+        assert!(error.to_string().contains("expected one of `°C`, `degC`"));
+    }
+}
@@ -0,0 +1,373 @@
+use core::ops::Div;
+
+use crate::{NativeType, Quantity, UnitError, UnitErrorKind};
+
+/// When a solver stops: once `x` changes by less than `x` or `|f(x)|` is within `y`
+#[derive(Copy, Clone)]
+pub struct Tolerance<X, Y> {
+    x: X,
+    y: Y,
+    max_iterations: usize,
+}
+
+impl<X: Quantity, Y: Quantity> Tolerance<X, Y> {
+    pub fn new(x: X, y: Y, max_iterations: usize) -> Self {
+        Self {
+            x,
+            y,
+            max_iterations,
+        }
+    }
+
+    fn x(&self) -> NativeType {
+        self.x.into()
+    }
+
+    fn y(&self) -> NativeType {
+        self.y.into()
+    }
+}
+
+/// The result of a solver with `y = f(x)` at the final estimate
+#[derive(Copy, Clone, Debug)]
+pub struct Solution<X, Y> {
+    x: X,
+    y: Y,
+    iterations: usize,
+    converged: bool,
+}
+
+impl<X: Quantity, Y: Quantity> Solution<X, Y> {
+    fn new(x: NativeType, y: NativeType, iterations: usize, converged: bool) -> Self {
+        Self {
+            x: X::from(x),
+            y: Y::from(y),
+            iterations,
+            converged,
+        }
+    }
+
+    pub fn x(&self) -> X {
+        self.x
+    }
+
+    pub fn y(&self) -> Y {
+        self.y
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// `false` if the solver ran out of iterations before meeting the tolerance
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+}
+
+fn finite<T: Quantity>(value: NativeType) -> Result<NativeType, UnitError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(UnitError::new::<T>(value, UnitErrorKind::NonFinite))
+    }
+}
+
+/// Evaluates `function` in natives, rejecting NaN and infinite results
+fn evaluate<X: Quantity, Y: Quantity>(
+    function: &impl Fn(X) -> Y,
+    x: NativeType,
+) -> Result<NativeType, UnitError> {
+    finite::<Y>(function(X::from(x)).into())
+}
+
+fn bracket<X: Quantity, Y: Quantity>(
+    function: &impl Fn(X) -> Y,
+    lower: X,
+    upper: X,
+) -> Result<(NativeType, NativeType, NativeType, NativeType), UnitError> {
+    let a = finite::<X>(lower.into())?;
+    let b = finite::<X>(upper.into())?;
+    let fa = evaluate(function, a)?;
+    let fb = evaluate(function, b)?;
+    if fa * fb > 0.0 {
+        return Err(UnitError::new::<Y>(fa, UnitErrorKind::NotBracketed));
+    }
+    Ok((a, b, fa, fb))
+}
+
+/// Finds a root of `function` between `lower` and `upper` by bisection
+pub fn bisection<X: Quantity, Y: Quantity>(
+    function: impl Fn(X) -> Y,
+    lower: X,
+    upper: X,
+    tolerance: Tolerance<X, Y>,
+) -> Result<Solution<X, Y>, UnitError> {
+    let (mut a, mut b, mut fa, fb) = bracket(&function, lower, upper)?;
+    if fa.abs() <= tolerance.y() {
+        return Ok(Solution::new(a, fa, 0, true));
+    }
+    if fb.abs() <= tolerance.y() {
+        return Ok(Solution::new(b, fb, 0, true));
+    }
+
+    let mut middle = (a + b) * 0.5;
+    let mut fm = evaluate(&function, middle)?;
+    for iteration in 1..=tolerance.max_iterations {
+        if fm.abs() <= tolerance.y() || (b - a).abs() * 0.5 <= tolerance.x() {
+            return Ok(Solution::new(middle, fm, iteration, true));
+        }
+        if fa * fm < 0.0 {
+            b = middle;
+        } else {
+            a = middle;
+            fa = fm;
+        }
+        middle = (a + b) * 0.5;
+        fm = evaluate(&function, middle)?;
+    }
+    Ok(Solution::new(middle, fm, tolerance.max_iterations, false))
+}
+
+/// Finds a root of `function` between `lower` and `upper` using Brent's method
+///
+/// This combines bisection with secant and inverse quadratic steps, so it
+/// is as robust as bisection but usually much faster.
+pub fn brent<X: Quantity, Y: Quantity>(
+    function: impl Fn(X) -> Y,
+    lower: X,
+    upper: X,
+    tolerance: Tolerance<X, Y>,
+) -> Result<Solution<X, Y>, UnitError> {
+    let (mut a, mut b, mut fa, mut fb) = bracket(&function, lower, upper)?;
+    let (mut c, mut fc) = (b, fb);
+    let (mut d, mut e) = (b - a, b - a);
+
+    for iteration in 0..tolerance.max_iterations {
+        if fb * fc > 0.0 {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let step_tolerance = 2.0 * NativeType::EPSILON * b.abs() + 0.5 * tolerance.x();
+        let half = 0.5 * (c - b);
+        if half.abs() <= step_tolerance || fb.abs() <= tolerance.y() {
+            return Ok(Solution::new(b, fb, iteration, true));
+        }
+
+        if e.abs() >= step_tolerance && fa.abs() > fb.abs() {
+            // try interpolation
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * half * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * half * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            let limit = (3.0 * half * q - (step_tolerance * q).abs()).min((e * q).abs());
+            if 2.0 * p < limit {
+                e = d;
+                d = p / q;
+            } else {
+                d = half;
+                e = d;
+            }
+        } else {
+            d = half;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > step_tolerance {
+            d
+        } else {
+            step_tolerance.copysign(half)
+        };
+        fb = evaluate(&function, b)?;
+    }
+    Ok(Solution::new(b, fb, tolerance.max_iterations, false))
+}
+
+/// Finds a root of `function` starting at `initial` using Newton's method
+///
+/// `derivative` returns the slope of `function`, so its type is `Y / X`.
+pub fn newton<X, Y, D>(
+    function: impl Fn(X) -> Y,
+    derivative: impl Fn(X) -> D,
+    initial: X,
+    tolerance: Tolerance<X, Y>,
+) -> Result<Solution<X, Y>, UnitError>
+where
+    X: Quantity,
+    Y: Quantity + Div<D, Output = X>,
+    D: Quantity,
+{
+    let mut x: NativeType = finite::<X>(initial.into())?;
+    let mut y = evaluate(&function, x)?;
+    for iteration in 0..tolerance.max_iterations {
+        if y.abs() <= tolerance.y() {
+            return Ok(Solution::new(x, y, iteration, true));
+        }
+        let step: NativeType = (Y::from(y) / derivative(X::from(x))).into();
+        x = finite::<X>(x - step)?;
+        y = evaluate(&function, x)?;
+        if step.abs() <= tolerance.x() {
+            return Ok(Solution::new(x, y, iteration + 1, true));
+        }
+    }
+    Ok(Solution::new(
+        x,
+        y,
+        tolerance.max_iterations,
+        y.abs() <= tolerance.y(),
+    ))
+}
+
+/// Finds the minimum of a unimodal `function` between `lower` and `upper`
+///
+/// The search stops once the bracket is narrower than `tolerance`.
+pub fn golden_section<X: Quantity, Y: Quantity>(
+    function: impl Fn(X) -> Y,
+    lower: X,
+    upper: X,
+    tolerance: X,
+    max_iterations: usize,
+) -> Result<Solution<X, Y>, UnitError> {
+    // 1 / golden ratio, (√5 - 1) / 2
+    #[cfg(feature = "f32")]
+    const RATIO: NativeType = 0.618_034;
+    #[cfg(not(feature = "f32"))]
+    const RATIO: NativeType = 0.618_033_988_749_894_9;
+
+    let tolerance: NativeType = tolerance.into();
+    let mut a = finite::<X>(lower.into())?;
+    let mut b = finite::<X>(upper.into())?;
+    let mut c = b - (b - a) * RATIO;
+    let mut d = a + (b - a) * RATIO;
+    let mut fc = evaluate(&function, c)?;
+    let mut fd = evaluate(&function, d)?;
+
+    for iteration in 0..max_iterations {
+        if (b - a).abs() <= tolerance {
+            let x = (a + b) * 0.5;
+            return Ok(Solution::new(x, evaluate(&function, x)?, iteration, true));
+        }
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - (b - a) * RATIO;
+            fc = evaluate(&function, c)?;
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + (b - a) * RATIO;
+            fd = evaluate(&function, d)?;
+        }
+    }
+    let x = (a + b) * 0.5;
+    Ok(Solution::new(
+        x,
+        evaluate(&function, x)?,
+        max_iterations,
+        false,
+    ))
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Area, ElectricCurrent, ElectricPotential, ElectricResistance, Length, Power};
+
+    // a load whose resistance rises as it heats up
+    fn dissipation(current: ElectricCurrent) -> Power {
+        let amps: NativeType = current.into();
+        let resistance = ElectricResistance::new(2.0 * (1.0 + 0.1 * amps));
+        (current * resistance) * current
+    }
+
+    fn excess(current: ElectricCurrent) -> Power {
+        dissipation(current) - Power::new(5.0)
+    }
+
+    // 2 I^2 + 0.2 I^3 = 5 W
+    const ROOT: NativeType = 1.475_963;
+
+    fn tolerance() -> Tolerance<ElectricCurrent, Power> {
+        Tolerance::new(ElectricCurrent::new(1e-6), Power::new(1e-6), 100)
+    }
+
+    fn assert_root(solution: Solution<ElectricCurrent, Power>) {
+        assert!(solution.converged());
+        assert!((solution.x() - ElectricCurrent::new(ROOT)).abs() < ElectricCurrent::new(1e-4));
+        assert!(solution.y().abs() < Power::new(1e-3));
+    }
+
+    #[test]
+    fn bracketing_solvers() {
+        let lower = ElectricCurrent::new(0.5);
+        let upper = ElectricCurrent::new(4.0);
+        let bisected = bisection(excess, lower, upper, tolerance()).unwrap();
+        assert_root(bisected);
+        let fast = brent(excess, lower, upper, tolerance()).unwrap();
+        assert_root(fast);
+        assert!(fast.iterations() < bisected.iterations());
+
+        let error = brent(excess, upper, ElectricCurrent::new(5.0), tolerance()).unwrap_err();
+        assert_eq!(error.kind(), UnitErrorKind::NotBracketed);
+        assert_eq!(error.quantity(), "Power");
+    }
+
+    #[test]
+    fn newton_solver() {
+        let slope = |current: ElectricCurrent| {
+            let amps: NativeType = current.into();
+            ElectricPotential::new(4.0 * amps + 0.6 * amps * amps)
+        };
+        let solution = newton(excess, slope, ElectricCurrent::new(3.0), tolerance()).unwrap();
+        assert_root(solution);
+
+        let flat = |_| ElectricPotential::new(0.0);
+        assert!(newton(excess, flat, ElectricCurrent::new(3.0), tolerance()).is_err());
+    }
+
+    #[test]
+    fn golden_section_minimum() {
+        let bowl = |x: Length| {
+            let offset = x - Length::new(3.0);
+            offset * offset + Area::new(2.0)
+        };
+        let solution = golden_section(
+            bowl,
+            Length::new(-5.0),
+            Length::new(8.0),
+            Length::new(1e-4),
+            100,
+        )
+        .unwrap();
+        assert!(solution.converged());
+        assert!((solution.x() - Length::new(3.0)).abs() < Length::new(1e-3));
+        assert_eq!(solution.y(), Area::new(2.0));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{NativeType, Quantity, Squared};
+
+#[cfg(feature = "f32")]
+fn sqrt(value: NativeType) -> NativeType {
+    libm::sqrtf(value)
+}
+
+#[cfg(not(feature = "f32"))]
+fn sqrt(value: NativeType) -> NativeType {
+    libm::sqrt(value)
+}
+
+/// Arithmetic mean or `None` if there are no samples
+pub fn mean<T: Quantity>(samples: impl IntoIterator<Item = T>) -> Option<T> {
+    let mut accumulator = Accumulator::new();
+    accumulator.extend(samples);
+    accumulator.mean()
+}
+
+/// Root mean square or `None` if there are no samples
+pub fn rms<T: Quantity>(samples: impl IntoIterator<Item = T>) -> Option<T> {
+    let mut accumulator = Accumulator::new();
+    accumulator.extend(samples);
+    accumulator.rms()
+}
+
+pub fn minimum<T: Quantity>(samples: impl IntoIterator<Item = T>) -> Option<T> {
+    samples
+        .into_iter()
+        .min_by(|a, b| Into::<NativeType>::into(*a).total_cmp(&(*b).into()))
+}
+
+pub fn maximum<T: Quantity>(samples: impl IntoIterator<Item = T>) -> Option<T> {
+    samples
+        .into_iter()
+        .max_by(|a, b| Into::<NativeType>::into(*a).total_cmp(&(*b).into()))
+}
+
+/// Population variance in the squared unit (e.g. `VelocitySquared` for `Velocity`)
+pub fn variance<T: Squared>(samples: impl IntoIterator<Item = T>) -> Option<T::Output> {
+    let mut accumulator = Accumulator::new();
+    accumulator.extend(samples);
+    accumulator.variance()
+}
+
+/// Population standard deviation
+pub fn standard_deviation<T: Quantity>(samples: impl IntoIterator<Item = T>) -> Option<T> {
+    let mut accumulator = Accumulator::new();
+    accumulator.extend(samples);
+    accumulator.standard_deviation()
+}
+
+fn sort<T: Quantity>(samples: &mut [T]) {
+    samples.sort_unstable_by(|a, b| Into::<NativeType>::into(*a).total_cmp(&(*b).into()));
+}
+
+/// Median of `samples`, which are sorted in place
+pub fn median<T: Quantity>(samples: &mut [T]) -> Option<T> {
+    percentile(samples, 50.0)
+}
+
+/// Percentile (0 to 100) of `samples`, which are sorted in place
+///
+/// Values between samples are linearly interpolated.
+pub fn percentile<T: Quantity>(samples: &mut [T], percent: NativeType) -> Option<T> {
+    if samples.is_empty() {
+        return None;
+    }
+    sort(samples);
+
+    let position = percent.clamp(0.0, 100.0) / 100.0 * (samples.len() - 1) as NativeType;
+    let index = position as usize;
+    let lower: NativeType = samples[index].into();
+    let upper: NativeType = samples[(index + 1).min(samples.len() - 1)].into();
+    Some(T::from(
+        lower + (upper - lower) * (position - index as NativeType),
+    ))
+}
+
+/// Online statistics using Welford's algorithm (no allocation)
+#[derive(Copy, Clone)]
+pub struct Accumulator<T> {
+    count: usize,
+    mean: NativeType,
+    m2: NativeType,
+    sum_of_squares: NativeType,
+    minimum: Option<T>,
+    maximum: Option<T>,
+}
+
+impl<T: Quantity> Accumulator<T> {
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            sum_of_squares: 0.0,
+            minimum: None,
+            maximum: None,
+        }
+    }
+
+    pub fn push(&mut self, sample: T) {
+        let value: NativeType = sample.into();
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as NativeType;
+        self.m2 += delta * (value - self.mean);
+        self.sum_of_squares += value * value;
+
+        if self.minimum.is_none_or(|minimum| value < minimum.into()) {
+            self.minimum = Some(sample);
+        }
+        if self.maximum.is_none_or(|maximum| value > maximum.into()) {
+            self.maximum = Some(sample);
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn mean(&self) -> Option<T> {
+        (self.count > 0).then(|| T::from(self.mean))
+    }
+
+    pub fn rms(&self) -> Option<T> {
+        (self.count > 0).then(|| T::from(sqrt(self.sum_of_squares / self.count as NativeType)))
+    }
+
+    pub fn minimum(&self) -> Option<T> {
+        self.minimum
+    }
+
+    pub fn maximum(&self) -> Option<T> {
+        self.maximum
+    }
+
+    fn population_variance(&self) -> Option<NativeType> {
+        (self.count > 0).then(|| self.m2 / self.count as NativeType)
+    }
+
+    fn sample_variance_native(&self) -> Option<NativeType> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as NativeType)
+    }
+
+    /// Population standard deviation
+    pub fn standard_deviation(&self) -> Option<T> {
+        self.population_variance()
+            .map(|variance| T::from(sqrt(variance)))
+    }
+
+    /// Sample standard deviation (with Bessel's correction)
+    pub fn sample_standard_deviation(&self) -> Option<T> {
+        self.sample_variance_native()
+            .map(|variance| T::from(sqrt(variance)))
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<T: Squared> Accumulator<T> {
+    /// Population variance
+    pub fn variance(&self) -> Option<T::Output> {
+        self.population_variance().map(T::Output::from)
+    }
+
+    /// Sample variance (with Bessel's correction)
+    pub fn sample_variance(&self) -> Option<T::Output> {
+        self.sample_variance_native().map(T::Output::from)
+    }
+}
+
+impl<T: Quantity> Default for Accumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Quantity> Extend<T> for Accumulator<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, samples: I) {
+        for sample in samples {
+            self.push(sample);
+        }
+    }
+}
+
+impl<T: Quantity> FromIterator<T> for Accumulator<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(samples: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(samples);
+        accumulator
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ElectricPotential, Velocity, VelocitySquared};
+
+    fn voltages() -> std::vec::Vec<ElectricPotential> {
+        [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .into_iter()
+            .map(ElectricPotential::new)
+            .collect()
+    }
+
+    #[test]
+    fn summary_statistics() {
+        let samples = voltages();
+        assert_eq!(mean(samples.clone()), Some(ElectricPotential::new(5.0)));
+        assert_eq!(
+            standard_deviation(samples.clone()),
+            Some(ElectricPotential::new(2.0))
+        );
+        assert_eq!(minimum(samples.clone()), Some(ElectricPotential::new(2.0)));
+        assert_eq!(maximum(samples.clone()), Some(ElectricPotential::new(9.0)));
+        assert_eq!(
+            rms(samples.clone()),
+            Some(ElectricPotential::new(29.0f64.sqrt() as NativeType))
+        );
+        assert!(mean(std::vec::Vec::<ElectricPotential>::new()).is_none());
+
+        let variance: Option<VelocitySquared> = variance([Velocity::new(3.0), Velocity::new(7.0)]);
+        assert_eq!(variance, Some(VelocitySquared::new(4.0)));
+    }
+
+    #[test]
+    fn order_statistics() {
+        let mut samples = voltages();
+        samples.reverse();
+        assert_eq!(median(&mut samples), Some(ElectricPotential::new(4.5)));
+        assert_eq!(
+            percentile(&mut samples, 100.0),
+            Some(ElectricPotential::new(9.0))
+        );
+        assert_eq!(
+            percentile(&mut samples, 25.0),
+            Some(ElectricPotential::new(4.0))
+        );
+        assert!(median::<ElectricPotential>(&mut []).is_none());
+    }
+
+    #[test]
+    fn welford_accumulator() {
+        let mut accumulator: Accumulator<Velocity> =
+            [2.0, 4.0, 6.0].into_iter().map(Velocity::new).collect();
+        assert_eq!(accumulator.count(), 3);
+        assert_eq!(accumulator.mean(), Some(Velocity::new(4.0)));
+        assert_eq!(
+            accumulator.sample_variance(),
+            Some(VelocitySquared::new(4.0))
+        );
+        assert_eq!(
+            accumulator.sample_standard_deviation(),
+            Some(Velocity::new(2.0))
+        );
+        assert_eq!(accumulator.minimum(), Some(Velocity::new(2.0)));
+        assert_eq!(accumulator.maximum(), Some(Velocity::new(6.0)));
+
+        accumulator.reset();
+        assert!(accumulator.variance().is_none());
+    }
+}
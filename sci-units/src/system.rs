@@ -0,0 +1,253 @@
+use crate::format::Scaling;
+use crate::imperial::{
+    Degrees, DegreesFahrenheit, Feet, FeetPerSecond, FeetPerSecondSquared, FootPounds,
+    InchesMercury, Knots, NauticalMiles, Pounds, PoundsForce, PoundsPerSquareInch,
+};
+use crate::render::Factor;
+use crate::{
+    Acceleration, Force, Length, Mass, NativeType, PlaneAngle, Prefix, Pressure, Quantity,
+    Temperature, Torque, Velocity,
+};
+
+/// The unit a quantity is presented in, which may differ from the unit it is stored in
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayUnit {
+    /// The `Quantity::NAME` of the type the unit belongs to (e.g. `"Feet"`)
+    pub name: &'static str,
+    pub symbol: &'static str,
+    pub ascii_symbol: &'static str,
+    pub singular: &'static str,
+    pub plural: &'static str,
+    pub unit: &'static [Factor],
+    pub prefixable: bool,
+    /// Converts a stored (SI) value into the unit
+    pub convert: fn(NativeType) -> NativeType,
+}
+
+fn identity(value: NativeType) -> NativeType {
+    value
+}
+
+fn convert<From: Quantity, Into: Quantity + core::convert::From<From>>(
+    value: NativeType,
+) -> NativeType {
+    Into::from(From::from(value)).into()
+}
+
+impl DisplayUnit {
+    /// The unit of `T` itself
+    pub const fn of<T: Quantity>() -> Self {
+        Self::with_conversion::<T>(identity)
+    }
+
+    /// Presents values of `From` in the unit of `Into` (e.g. `Length` in `imperial::Feet`)
+    pub const fn converted<From: Quantity, Into: Quantity + core::convert::From<From>>() -> Self {
+        Self::with_conversion::<Into>(convert::<From, Into>)
+    }
+
+    const fn with_conversion<T: Quantity>(convert: fn(NativeType) -> NativeType) -> Self {
+        Self {
+            name: T::NAME,
+            symbol: T::SYMBOL,
+            ascii_symbol: T::ASCII_SYMBOL,
+            singular: T::SINGULAR,
+            plural: T::PLURAL,
+            unit: T::UNIT,
+            prefixable: T::PREFIXABLE,
+            convert,
+        }
+    }
+}
+
+/// How a unit system presents one quantity
+#[derive(Copy, Clone, Debug)]
+pub struct Preference {
+    /// The `Quantity::NAME` of the stored quantity (e.g. `"Length"`)
+    pub quantity: &'static str,
+    pub unit: DisplayUnit,
+    pub scaling: Scaling,
+}
+
+impl Preference {
+    /// Presents `T` in its own unit with `scaling`
+    pub const fn scaled<T: Quantity>(scaling: Scaling) -> Self {
+        Self {
+            quantity: T::NAME,
+            unit: DisplayUnit::of::<T>(),
+            scaling,
+        }
+    }
+
+    /// Presents `From` in the unit of `Into` (e.g. `Velocity` in `imperial::Knots`)
+    pub const fn converted<From: Quantity, Into: Quantity + core::convert::From<From>>() -> Self {
+        Self {
+            quantity: From::NAME,
+            unit: DisplayUnit::converted::<From, Into>(),
+            scaling: Scaling::None,
+        }
+    }
+}
+
+/// A display profile: the unit and prefix each quantity is presented in
+///
+/// Stored values are always SI; a unit system only changes how they are
+/// formatted and serialised. Quantities without a preference use their SI
+/// unit with the default `scaling`.
+#[derive(Copy, Clone, Debug)]
+pub struct UnitSystem<'a> {
+    pub name: &'a str,
+    pub scaling: Scaling,
+    pub preferences: &'a [Preference],
+}
+
+impl UnitSystem<'static> {
+    /// SI units without prefixes
+    pub const SI: UnitSystem<'static> = UnitSystem::new("SI", Scaling::None, &[]);
+
+    /// SI units with engineering prefixes (e.g. `"4.7 kΩ"`, `"101.3 kPa"`)
+    pub const METRIC_ENGINEERING: UnitSystem<'static> =
+        UnitSystem::new("metric engineering", Scaling::Automatic, &[]);
+
+    /// Feet, pounds, pounds-force, psi and degrees Fahrenheit
+    pub const US_CUSTOMARY: UnitSystem<'static> = UnitSystem::new(
+        "US customary",
+        Scaling::None,
+        &[
+            Preference::converted::<Length, Feet>(),
+            Preference::converted::<Velocity, FeetPerSecond>(),
+            Preference::converted::<Acceleration, FeetPerSecondSquared>(),
+            Preference::converted::<Mass, Pounds>(),
+            Preference::converted::<Force, PoundsForce>(),
+            Preference::converted::<Pressure, PoundsPerSquareInch>(),
+            Preference::converted::<Torque, FootPounds>(),
+            Preference::converted::<Temperature, DegreesFahrenheit>(),
+            Preference::converted::<PlaneAngle, Degrees>(),
+        ],
+    );
+
+    /// Altitude in feet, speed in knots, pressure in inches of mercury
+    pub const AVIATION: UnitSystem<'static> = UnitSystem::new(
+        "aviation",
+        Scaling::None,
+        &[
+            Preference::converted::<Length, Feet>(),
+            Preference::converted::<Velocity, Knots>(),
+            Preference::converted::<Pressure, InchesMercury>(),
+            Preference::converted::<PlaneAngle, Degrees>(),
+        ],
+    );
+
+    /// Distance in nautical miles, speed in knots, pressure in hectopascals
+    pub const MARINE: UnitSystem<'static> = UnitSystem::new(
+        "marine",
+        Scaling::None,
+        &[
+            Preference::converted::<Length, NauticalMiles>(),
+            Preference::converted::<Velocity, Knots>(),
+            Preference::scaled::<Pressure>(Scaling::Fixed(Prefix::Hecto)),
+            Preference::converted::<PlaneAngle, Degrees>(),
+        ],
+    );
+}
+
+impl<'a> UnitSystem<'a> {
+    pub const fn new(name: &'a str, scaling: Scaling, preferences: &'a [Preference]) -> Self {
+        Self {
+            name,
+            scaling,
+            preferences,
+        }
+    }
+
+    /// How `T` is presented in this system
+    pub fn preference<T: Quantity>(&self) -> Preference {
+        self.preferences
+            .iter()
+            .find(|preference| preference.quantity == T::NAME)
+            .copied()
+            .unwrap_or(Preference::scaled::<T>(self.scaling))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ElectricResistance, Time};
+
+    #[test]
+    fn profiles() {
+        let altitude = Length::new(3_048.0);
+        assert_eq!(altitude.present(&UnitSystem::SI).to_string(), "3048 meters");
+        assert_eq!(
+            altitude
+                .present(&UnitSystem::METRIC_ENGINEERING)
+                .to_string(),
+            "3.048 kilometers"
+        );
+        assert_eq!(
+            altitude.present(&UnitSystem::AVIATION).to_string(),
+            "10000 feet"
+        );
+        assert_eq!(
+            Length::new(1_852.0)
+                .present(&UnitSystem::MARINE)
+                .to_string(),
+            "1 nautical mile"
+        );
+        assert_eq!(
+            Pressure::new(101_325.0)
+                .present(&UnitSystem::MARINE)
+                .to_string(),
+            "1013.25 hectopascals"
+        );
+        assert_eq!(
+            Temperature::new(100.0)
+                .present(&UnitSystem::US_CUSTOMARY)
+                .symbols()
+                .to_string(),
+            "212 °F"
+        );
+        assert_eq!(
+            ElectricResistance::new(4_700.0)
+                .present(&UnitSystem::METRIC_ENGINEERING)
+                .to_string(),
+            "4.7 kiloohms"
+        );
+        // quantities without a preference keep their SI unit
+        assert_eq!(
+            Time::new(90.0).present(&UnitSystem::AVIATION).to_string(),
+            "90 seconds"
+        );
+        // the stored value is unchanged
+        assert_eq!(altitude.native, 3_048.0);
+    }
+
+    #[test]
+    fn custom_profile() {
+        const SURVEY: UnitSystem = UnitSystem::new(
+            "survey",
+            Scaling::None,
+            &[Preference::scaled::<Length>(Scaling::Fixed(Prefix::Kilo))],
+        );
+        assert_eq!(
+            Length::new(1_500.0).present(&SURVEY).symbols().to_string(),
+            "1.5 km"
+        );
+        let preference = SURVEY.preference::<Velocity>();
+        assert_eq!(preference.quantity, "Velocity");
+        assert_eq!(preference.unit.symbol, "m/s");
+    }
+
+    #[test]
+    fn serialised_in_preferred_unit() {
+        let altitude = Length::new(3_048.0);
+        let json = serde_json::to_string(&altitude.present(&UnitSystem::AVIATION)).unwrap();
+        assert_eq!(json, "\"10000 feet\"");
+        let restored: Length = serde_json::from_str::<String>(&json)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((restored.native - 3_048.0).abs() < 1e-3);
+    }
+}